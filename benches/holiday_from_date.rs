@@ -0,0 +1,22 @@
+use chrono::NaiveDate;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use holiday_de::GermanRegion;
+
+fn holiday_from_date(c: &mut Criterion) {
+    let start = NaiveDate::from_ymd_opt(2019, 1, 1).unwrap();
+    let dates: Vec<NaiveDate> = start.iter_days().take(365).collect();
+    c.bench_function("holiday_from_date for all days of 2019", |b| {
+        b.iter(|| {
+            for date in &dates {
+                black_box(GermanRegion::Bayern.holiday_from_date(black_box(*date)));
+            }
+        })
+    });
+    c.bench_function("holiday_from_date for a fixed date holiday", |b| {
+        let date = NaiveDate::from_ymd_opt(2019, 10, 3).unwrap();
+        b.iter(|| GermanRegion::Bayern.holiday_from_date(black_box(date)))
+    });
+}
+
+criterion_group!(benches, holiday_from_date);
+criterion_main!(benches);
@@ -0,0 +1,18 @@
+//! Compiles against `holiday_de` without the standard library:
+//!
+//! `cargo build --example no_std --no-default-features`
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use chrono::NaiveDate;
+use holiday_de::{DateExt, GermanHoliday, GermanRegion};
+
+pub fn holidays(region: GermanRegion, year: i32) -> Vec<(NaiveDate, GermanHoliday)> {
+    region.holiday_dates_in_year(year)
+}
+
+pub fn is_holiday(date: NaiveDate) -> bool {
+    date.is_public_holiday_in(GermanRegion::Bayern)
+}
@@ -0,0 +1,84 @@
+use alloc::collections::BTreeMap;
+use chrono::NaiveDate;
+
+use crate::holidays::GermanHoliday;
+use crate::regions::GermanRegion;
+
+/// Public holidays of a region for a single year, calculated once.
+///
+/// Prefer this over `GermanRegion` for repeated lookups within the same year.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HolidayCalendar {
+    region: GermanRegion,
+    year: i32,
+    map: BTreeMap<NaiveDate, GermanHoliday>,
+}
+
+impl HolidayCalendar {
+    /// Calculates all public holidays of the region in the given year.
+    ///
+    /// For years before 1995 the calendar will be empty.
+    pub fn new(region: GermanRegion, year: i32) -> HolidayCalendar {
+        HolidayCalendar {
+            region,
+            year,
+            map: region.holiday_dates_in_year(year).into_iter().collect(),
+        }
+    }
+
+    pub fn region(&self) -> GermanRegion {
+        self.region
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Checks if a given date is a public holiday.
+    ///
+    /// Always `false` for dates outside of the calendar's year.
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.map.contains_key(&date)
+    }
+
+    /// Returns the holiday for a specific date if the date is a public holiday.
+    ///
+    /// Always `None` for dates outside of the calendar's year.
+    pub fn holiday(&self, date: NaiveDate) -> Option<GermanHoliday> {
+        self.map.get(&date).cloned()
+    }
+
+    /// Iterates over all public holidays and their dates ordered by date.
+    pub fn iter(&self) -> impl Iterator<Item = (NaiveDate, GermanHoliday)> + '_ {
+        self.map.iter().map(|(date, holiday)| (*date, *holiday))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::regions::GermanRegion::*;
+    use crate::tests::ymd;
+    use chrono::Datelike;
+
+    #[test]
+    fn calendar_matches_region() {
+        for region in GermanRegion::all() {
+            let calendar = HolidayCalendar::new(*region, 2019);
+            let first_day = ymd(2019, 1, 1);
+            for date in first_day.iter_days().take_while(|date| date.year() == 2019) {
+                assert_eq!(region.holiday_from_date(date), calendar.holiday(date));
+                assert_eq!(region.is_holiday(date), calendar.is_holiday(date));
+            }
+        }
+    }
+
+    #[test]
+    fn calendar_iterates_in_date_order() {
+        let calendar = HolidayCalendar::new(Bayern, 2019);
+        assert_eq!(
+            Bayern.holiday_dates_in_year(2019),
+            calendar.iter().collect::<Vec<_>>()
+        );
+    }
+}
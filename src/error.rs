@@ -0,0 +1,61 @@
+use core::fmt;
+
+/// Error for years before 1995, for which no holidays are provided.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UnsupportedYear {
+    year: i32,
+}
+
+impl UnsupportedYear {
+    pub(crate) fn new(year: i32) -> UnsupportedYear {
+        UnsupportedYear { year }
+    }
+
+    /// The year which has been requested.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+}
+
+impl fmt::Display for UnsupportedYear {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "holidays are only supported since 1995, but {} was requested",
+            self.year
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnsupportedYear {}
+
+/// Error for indices which do not correspond to a `GermanRegion`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct InvalidRegionIndex {
+    index: u8,
+}
+
+impl InvalidRegionIndex {
+    pub(crate) fn new(index: u8) -> InvalidRegionIndex {
+        InvalidRegionIndex { index }
+    }
+
+    /// The index which has been requested.
+    pub fn index(&self) -> u8 {
+        self.index
+    }
+}
+
+impl fmt::Display for InvalidRegionIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "region indices range from 0 to 15, but {} was requested",
+            self.index
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidRegionIndex {}
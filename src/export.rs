@@ -0,0 +1,179 @@
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use chrono::Duration;
+use core::fmt::Write;
+
+use crate::regions::GermanRegion;
+
+impl GermanRegion {
+    /// Exports all public holidays in the given year as iCalendar (RFC 5545),
+    /// with one all-day event per holiday.
+    ///
+    /// For years before 1995 the calendar contains no events.
+    pub fn to_ical(&self, year: i32) -> String {
+        let mut ical = String::new();
+        ical.push_str("BEGIN:VCALENDAR\r\n");
+        ical.push_str("VERSION:2.0\r\n");
+        ical.push_str("PRODID:-//holiday_de//Feiertage//DE\r\n");
+        for (date, holiday) in self.holiday_dates_in_year(year) {
+            let start = date.format("%Y%m%d");
+            let end = (date + Duration::days(1)).format("%Y%m%d");
+            ical.push_str("BEGIN:VEVENT\r\n");
+            let _ = write!(
+                ical,
+                "UID:{}-{}-{}@holiday_de\r\n",
+                self.iso_code(),
+                holiday.variant_name(),
+                year
+            );
+            let _ = write!(ical, "DTSTAMP:{}T000000Z\r\n", start);
+            let _ = write!(ical, "DTSTART;VALUE=DATE:{}\r\n", start);
+            let _ = write!(ical, "DTEND;VALUE=DATE:{}\r\n", end);
+            let _ = write!(ical, "SUMMARY:{}\r\n", escape_ical(holiday.description()));
+            ical.push_str("END:VEVENT\r\n");
+        }
+        ical.push_str("END:VCALENDAR\r\n");
+        ical
+    }
+
+    /// Exports all public holidays in the given year as CSV (RFC 4180),
+    /// with a header row followed by `date,description` rows ordered by date.
+    ///
+    /// For years before 1995 the CSV only contains the header.
+    pub fn to_csv(&self, year: i32) -> String {
+        let mut csv = String::from("date,description\r\n");
+        for (date, holiday) in self.holiday_dates_in_year(year) {
+            let _ = write!(
+                csv,
+                "{},{}\r\n",
+                date.format("%Y-%m-%d"),
+                escape_csv(holiday.description())
+            );
+        }
+        csv
+    }
+
+    /// Exports all public holidays in the given year as a JSON array ordered by date,
+    /// e.g. `[{"date":"2019-01-01","holiday":"Neujahr","description":"Neujahr"}]`.
+    ///
+    /// This does not require the `serde` feature.
+    /// For years before 1995 the array will be empty.
+    pub fn to_json(&self, year: i32) -> String {
+        let entries: Vec<String> = self
+            .holiday_dates_in_year(year)
+            .into_iter()
+            .map(|(date, holiday)| {
+                format!(
+                    "{{\"date\":\"{}\",\"holiday\":\"{}\",\"description\":\"{}\"}}",
+                    date.format("%Y-%m-%d"),
+                    holiday.variant_name(),
+                    escape_json(holiday.description())
+                )
+            })
+            .collect();
+        format!("[{}]", entries.join(","))
+    }
+}
+
+fn escape_ical(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | ',' | ';' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn escape_csv(text: &str) -> String {
+    if text.contains(&[',', '"', '\r', '\n'][..]) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_owned()
+    }
+}
+
+fn escape_json(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::regions::GermanRegion::*;
+
+    #[test]
+    fn ical_contains_event_per_holiday() {
+        let ical = Bayern.to_ical(2019);
+        assert!(ical.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ical.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(13, ical.matches("BEGIN:VEVENT").count());
+        assert_eq!(13, ical.matches("END:VEVENT").count());
+        assert!(ical.contains("UID:DE-BY-Neujahr-2019@holiday_de\r\n"));
+        assert!(ical.contains("DTSTART;VALUE=DATE:20191003\r\n"));
+        assert!(ical.contains("DTEND;VALUE=DATE:20191004\r\n"));
+        assert!(ical.contains("SUMMARY:Tag der Deutschen Einheit\r\n"));
+    }
+
+    #[test]
+    fn ical_escapes_text() {
+        assert_eq!(r"a\, b\; c\\", escape_ical(r"a, b; c\"));
+    }
+
+    #[test]
+    fn csv_contains_row_per_holiday() {
+        let csv = Bayern.to_csv(2019);
+        let mut lines = csv.lines();
+        assert_eq!(Some("date,description"), lines.next());
+        assert_eq!(Some("2019-01-01,Neujahr"), lines.next());
+        assert_eq!(12, lines.count());
+    }
+
+    #[test]
+    fn csv_escapes_text() {
+        assert_eq!("Neujahr", escape_csv("Neujahr"));
+        assert_eq!("\"a, \"\"b\"\"\"", escape_csv("a, \"b\""));
+    }
+
+    #[test]
+    fn json_contains_entry_per_holiday() {
+        let json: serde_json::Value = serde_json::from_str(&Bayern.to_json(2019)).unwrap();
+        let entries = json.as_array().unwrap();
+        assert_eq!(13, entries.len());
+        assert_eq!(
+            serde_json::json!({
+                "date": "2019-11-01",
+                "holiday": "Allerheiligen",
+                "description": "Allerheiligen"
+            }),
+            entries[10]
+        );
+        assert_eq!("[]", Bayern.to_json(1994));
+    }
+
+    #[test]
+    fn json_escapes_text() {
+        assert_eq!(r#"\"a\\b\"\n\u0001"#, escape_json("\"a\\b\"\n\u{1}"));
+    }
+}
@@ -0,0 +1,140 @@
+use core::iter::FromIterator;
+
+use crate::holidays::GermanHoliday;
+
+/// Set of holidays stored as a bitset, providing constant time membership tests.
+///
+/// Iteration follows the declaration order of `GermanHoliday`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct HolidaySet {
+    // One bit per variant, `GermanHoliday` has more than 32 variants.
+    bits: u64,
+}
+
+impl HolidaySet {
+    /// Creates an empty set.
+    pub fn new() -> HolidaySet {
+        HolidaySet { bits: 0 }
+    }
+
+    /// True if the holiday is contained within the set.
+    pub fn contains(&self, holiday: GermanHoliday) -> bool {
+        self.bits & bit(holiday) != 0
+    }
+
+    /// Adds the holiday to the set.
+    ///
+    /// Returns `false` if the holiday was already contained.
+    pub fn insert(&mut self, holiday: GermanHoliday) -> bool {
+        let contained = self.contains(holiday);
+        self.bits |= bit(holiday);
+        !contained
+    }
+
+    /// Returns the number of holidays within the set.
+    pub fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    /// True if the set contains no holidays.
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    /// Iterates over the holidays in declaration order.
+    pub fn iter(&self) -> HolidaySetIter {
+        HolidaySetIter { bits: self.bits }
+    }
+}
+
+fn bit(holiday: GermanHoliday) -> u64 {
+    1 << holiday as u8
+}
+
+/// Iterator over the holidays of a `HolidaySet`.
+#[derive(Clone, Debug)]
+pub struct HolidaySetIter {
+    bits: u64,
+}
+
+impl Iterator for HolidaySetIter {
+    type Item = GermanHoliday;
+
+    fn next(&mut self) -> Option<GermanHoliday> {
+        if self.bits == 0 {
+            return None;
+        }
+        let index = self.bits.trailing_zeros() as usize;
+        self.bits &= self.bits - 1;
+        GermanHoliday::all().get(index).copied()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bits.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl IntoIterator for HolidaySet {
+    type Item = GermanHoliday;
+    type IntoIter = HolidaySetIter;
+
+    fn into_iter(self) -> HolidaySetIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<GermanHoliday> for HolidaySet {
+    fn from_iter<I: IntoIterator<Item = GermanHoliday>>(iter: I) -> Self {
+        let mut set = HolidaySet::new();
+        for holiday in iter {
+            set.insert(holiday);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::holidays::GermanHoliday::*;
+    use crate::regions::GermanRegion;
+
+    #[test]
+    fn insert_and_contains() {
+        let mut set = HolidaySet::new();
+        assert!(set.is_empty());
+        assert!(set.insert(Silvester));
+        assert!(set.insert(Neujahr));
+        assert!(!set.insert(Neujahr));
+        assert_eq!(2, set.len());
+        assert!(set.contains(Neujahr));
+        assert!(set.contains(Silvester));
+        assert!(!set.contains(Karfreitag));
+        assert_eq!(vec![Neujahr, Silvester], set.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn contains_all_holidays() {
+        let set: HolidaySet = GermanHoliday::all().iter().copied().collect();
+        assert_eq!(GermanHoliday::all().len(), set.len());
+        assert_eq!(
+            GermanHoliday::all().to_vec(),
+            set.into_iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn membership_matches_holidays_in_year() {
+        for region in GermanRegion::all() {
+            for year in &[1994, 2017, 2019, 2025] {
+                let holidays = region.holidays_in_year(*year);
+                let set = region.holiday_set_in_year(*year);
+                assert_eq!(holidays.len(), set.len());
+                for holiday in GermanHoliday::all() {
+                    assert_eq!(holidays.contains(holiday), set.contains(*holiday));
+                }
+            }
+        }
+    }
+}
@@ -1,12 +1,28 @@
-use chrono::{Datelike, Duration, NaiveDate};
-use computus;
+use alloc::vec::Vec;
+use chrono::{Datelike, Days, Duration, NaiveDate, Weekday};
+use core::cmp::Ordering;
+use core::ops::RangeInclusive;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::regions::BUNDESWEITE_FEIERTAGE;
+use crate::util::nth_weekday_of_month;
 
 /// All reoccurring holidays in Germany.
 /// This list contains both public and non-public holidays.
 ///
 /// For public holidays use `GermanRegion` instead, since
 /// public holidays differ from region to region.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+///
+/// With the `serde` feature enabled, holidays are serialized by their variant name,
+/// e.g. `"ChristiHimmelfahrt"`.
+///
+/// The enum is `#[non_exhaustive]`, since new holidays are added over time,
+/// e.g. Volkstrauertag and Muttertag. Matches outside of this crate must include a wildcard arm.
+/// Use `GermanHoliday::all()` to iterate over all holidays known to the current version.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
 pub enum GermanHoliday {
     Neujahr,
     HeiligeDreiKoenige,
@@ -19,6 +35,7 @@ pub enum GermanHoliday {
     Ostermontag,
     ErsterMai,
     TagDerBefreiung,
+    Muttertag,
     ChristiHimmelfahrt,
     Pfingstsonntag,
     Pfingstmontag,
@@ -28,52 +45,458 @@ pub enum GermanHoliday {
     MariaeHimmelfahrt,
     Weltkindertag,
     TagDerDeutschenEinheit,
+    Erntedankfest,
     Reformationstag,
     Allerheiligen,
+    Volkstrauertag,
     BussUndBettag,
+    Totensonntag,
+    ErsterAdvent,
+    ZweiterAdvent,
+    DritterAdvent,
+    VierterAdvent,
+    Nikolaus,
     Heiligabend,
     ErsterWeihnachtsfeiertag,
     ZweiterWeihnachtsfeiertag,
     Silvester,
 }
 
+/// Classification of holidays by their origin.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum HolidayCategory {
+    /// Holidays of the christian church year.
+    Christian,
+    /// Holidays of political or civil origin.
+    Secular,
+    /// Customs which are neither, e.g. Silvester.
+    Other,
+}
+
+/// Method to calculate easter sunday.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum EasterMethod {
+    /// Western easter, which applies to all German holidays.
+    #[default]
+    Gregorian,
+    /// Orthodox easter calculated within the Julian calendar, converted to a Gregorian date.
+    JulianOrthodox,
+}
+
+/// Language of holiday names, see `GermanHoliday::localized_name`.
+///
+/// More languages may be added in the future.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Language {
+    /// German, as returned by `GermanHoliday::description`.
+    #[default]
+    De,
+    /// English, as returned by `GermanHoliday::english_name`.
+    En,
+}
+
+/// Rule to move holidays falling on a weekend.
+///
+/// German law does not move holidays, so only `SubstitutionRule::None` is statutory.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum SubstitutionRule {
+    /// Keeps the exact date of the holiday.
+    #[default]
+    None,
+    /// Moves holidays on a saturday to the previous friday
+    /// and holidays on a sunday to the next monday. This is not statutory in Germany,
+    /// but mirrors the "observed" holidays of other countries for scheduling purposes.
+    NearestWeekday,
+}
+
 use GermanHoliday::*;
 
 impl GermanHoliday {
+    /// Returns all holidays in declaration order.
+    pub fn all() -> &'static [GermanHoliday] {
+        &[
+            Neujahr,
+            HeiligeDreiKoenige,
+            Frauentag,
+            Faschingsdienstag,
+            Aschermittwoch,
+            Gruendonnerstag,
+            Karfreitag,
+            Ostersonntag,
+            Ostermontag,
+            ErsterMai,
+            TagDerBefreiung,
+            Muttertag,
+            ChristiHimmelfahrt,
+            Pfingstsonntag,
+            Pfingstmontag,
+            SiebzehnterJuni,
+            Fronleichnam,
+            AugsburgerFriedensfest,
+            MariaeHimmelfahrt,
+            Weltkindertag,
+            TagDerDeutschenEinheit,
+            Erntedankfest,
+            Reformationstag,
+            Allerheiligen,
+            Volkstrauertag,
+            BussUndBettag,
+            Totensonntag,
+            ErsterAdvent,
+            ZweiterAdvent,
+            DritterAdvent,
+            VierterAdvent,
+            Nikolaus,
+            Heiligabend,
+            ErsterWeihnachtsfeiertag,
+            ZweiterWeihnachtsfeiertag,
+            Silvester,
+        ]
+    }
+
+    /// Returns the holiday falling on the given date, regardless of any region.
+    ///
+    /// This includes non-public holidays like `Heiligabend`.
+    /// If several holidays fall on the same date, e.g. `ErsterMai` and `ChristiHimmelfahrt` in 2008,
+    /// the holiday declared first within `GermanHoliday` is returned.
+    pub fn from_date(date: NaiveDate) -> Option<GermanHoliday> {
+        GermanHoliday::all()
+            .iter()
+            .cloned()
+            .find(|holiday| holiday.matches(date))
+    }
+
+    /// True if the holiday falls on the given date.
+    pub fn matches(&self, date: NaiveDate) -> bool {
+        self.date(date.year()) == Some(date)
+    }
+
     /// Calculates the date for a specific year.
     ///
     /// `None` if it cannot be calculated.
     pub fn date(&self, year: i32) -> Option<NaiveDate> {
+        self.date_with_easter(year, EasterMethod::Gregorian)
+    }
+
+    /// Calculates the date for a specific year.
+    ///
+    /// # Panics
+    /// If the date cannot be calculated, which can only happen for years outside of 1583 to 9999.
+    pub fn date_or_panic(&self, year: i32) -> NaiveDate {
+        self.date(year).unwrap_or_else(|| {
+            panic!(
+                "cannot calculate date of {} in year {}",
+                self.description(),
+                year
+            )
+        })
+    }
+
+    /// Calculates the dates for all years within the range.
+    ///
+    /// Years for which the date cannot be calculated are skipped.
+    pub fn dates_in_range(&self, years: RangeInclusive<i32>) -> Vec<(i32, NaiveDate)> {
+        years
+            .flat_map(|year| self.date(year).map(|date| (year, date)))
+            .collect()
+    }
+
+    /// Calculates the date for a specific year, moved according to the substitution rule.
+    ///
+    /// `None` if it cannot be calculated.
+    pub fn observed_date(&self, year: i32, rule: SubstitutionRule) -> Option<NaiveDate> {
+        let date = self.date(year)?;
+        match rule {
+            SubstitutionRule::None => Some(date),
+            SubstitutionRule::NearestWeekday => match date.weekday() {
+                Weekday::Sat => date.pred_opt(),
+                Weekday::Sun => date.succ_opt(),
+                _ => Some(date),
+            },
+        }
+    }
+
+    /// Calculates the date for a specific year, using the given method to calculate easter sunday.
+    /// Only holidays relative to easter sunday are affected by the method.
+    ///
+    /// `None` if it cannot be calculated.
+    pub fn date_with_easter(&self, year: i32, method: EasterMethod) -> Option<NaiveDate> {
         match self {
             Neujahr => date(year, 1, 1),
             HeiligeDreiKoenige => date(year, 1, 6),
             Frauentag => date(year, 3, 8),
-            Faschingsdienstag => relative_to_easter_sunday(year, -47),
-            Aschermittwoch => relative_to_easter_sunday(year, -46),
-            Gruendonnerstag => relative_to_easter_sunday(year, -3),
-            Karfreitag => relative_to_easter_sunday(year, -2),
-            Ostersonntag => relative_to_easter_sunday(year, 0),
-            Ostermontag => relative_to_easter_sunday(year, 1),
+            Faschingsdienstag => relative_to_easter_sunday(year, -47, method),
+            Aschermittwoch => relative_to_easter_sunday(year, -46, method),
+            Gruendonnerstag => relative_to_easter_sunday(year, -3, method),
+            Karfreitag => relative_to_easter_sunday(year, -2, method),
+            Ostersonntag => relative_to_easter_sunday(year, 0, method),
+            Ostermontag => relative_to_easter_sunday(year, 1, method),
             ErsterMai => date(year, 5, 1),
             TagDerBefreiung => date(year, 5, 8),
-            ChristiHimmelfahrt => relative_to_easter_sunday(year, 39),
-            Pfingstsonntag => relative_to_easter_sunday(year, 49),
-            Pfingstmontag => relative_to_easter_sunday(year, 50),
+            Muttertag => nth_weekday_of_month(year, 5, Weekday::Sun, 2),
+            ChristiHimmelfahrt => relative_to_easter_sunday(year, 39, method),
+            Pfingstsonntag => relative_to_easter_sunday(year, 49, method),
+            Pfingstmontag => relative_to_easter_sunday(year, 50, method),
             SiebzehnterJuni => date(year, 6, 17),
-            Fronleichnam => relative_to_easter_sunday(year, 60),
+            Fronleichnam => relative_to_easter_sunday(year, 60, method),
             AugsburgerFriedensfest => date(year, 8, 8),
             MariaeHimmelfahrt => date(year, 8, 15),
             Weltkindertag => date(year, 9, 20),
             TagDerDeutschenEinheit => date(year, 10, 3),
+            Erntedankfest => nth_weekday_of_month(year, 10, Weekday::Sun, 1),
             Reformationstag => date(year, 10, 31),
             Allerheiligen => date(year, 11, 1),
+            Volkstrauertag => relative_to_fourth_advent(year, -35),
             BussUndBettag => bus_und_bettag(year),
+            Totensonntag => relative_to_fourth_advent(year, -28),
+            ErsterAdvent => relative_to_fourth_advent(year, -21),
+            ZweiterAdvent => relative_to_fourth_advent(year, -14),
+            DritterAdvent => relative_to_fourth_advent(year, -7),
+            VierterAdvent => relative_to_fourth_advent(year, 0),
+            Nikolaus => date(year, 12, 6),
             Heiligabend => date(year, 12, 24),
             ErsterWeihnachtsfeiertag => date(year, 12, 25),
             ZweiterWeihnachtsfeiertag => date(year, 12, 26),
             Silvester => date(year, 12, 31),
         }
     }
+    /// Calculates the weekday for a specific year.
+    ///
+    /// `None` if the date cannot be calculated.
+    pub fn weekday(&self, year: i32) -> Option<Weekday> {
+        self.date(year).map(|date| date.weekday())
+    }
+
+    /// Returns month and day for holidays with a fixed date, e.g. `(5, 1)` for `ErsterMai`.
+    ///
+    /// `None` for movable holidays.
+    pub fn fixed_date(&self) -> Option<(u32, u32)> {
+        match self {
+            Neujahr => Some((1, 1)),
+            HeiligeDreiKoenige => Some((1, 6)),
+            Frauentag => Some((3, 8)),
+            ErsterMai => Some((5, 1)),
+            TagDerBefreiung => Some((5, 8)),
+            SiebzehnterJuni => Some((6, 17)),
+            AugsburgerFriedensfest => Some((8, 8)),
+            MariaeHimmelfahrt => Some((8, 15)),
+            Weltkindertag => Some((9, 20)),
+            TagDerDeutschenEinheit => Some((10, 3)),
+            Reformationstag => Some((10, 31)),
+            Allerheiligen => Some((11, 1)),
+            Nikolaus => Some((12, 6)),
+            Heiligabend => Some((12, 24)),
+            ErsterWeihnachtsfeiertag => Some((12, 25)),
+            ZweiterWeihnachtsfeiertag => Some((12, 26)),
+            Silvester => Some((12, 31)),
+            _ => None,
+        }
+    }
+
+    /// Returns the holiday with the given fixed date, the inverse of `GermanHoliday::fixed_date`.
+    pub(crate) fn from_fixed_date(month: u32, day: u32) -> Option<GermanHoliday> {
+        match (month, day) {
+            (1, 1) => Some(Neujahr),
+            (1, 6) => Some(HeiligeDreiKoenige),
+            (3, 8) => Some(Frauentag),
+            (5, 1) => Some(ErsterMai),
+            (5, 8) => Some(TagDerBefreiung),
+            (6, 17) => Some(SiebzehnterJuni),
+            (8, 8) => Some(AugsburgerFriedensfest),
+            (8, 15) => Some(MariaeHimmelfahrt),
+            (9, 20) => Some(Weltkindertag),
+            (10, 3) => Some(TagDerDeutschenEinheit),
+            (10, 31) => Some(Reformationstag),
+            (11, 1) => Some(Allerheiligen),
+            (12, 6) => Some(Nikolaus),
+            (12, 24) => Some(Heiligabend),
+            (12, 25) => Some(ErsterWeihnachtsfeiertag),
+            (12, 26) => Some(ZweiterWeihnachtsfeiertag),
+            (12, 31) => Some(Silvester),
+            _ => None,
+        }
+    }
+
+    /// Returns the month in which the holiday typically takes place.
+    ///
+    /// Movable holidays use their month within a representative year (2019),
+    /// e.g. `6` for Fronleichnam, which can also take place in May.
+    pub fn approx_month(&self) -> u32 {
+        if let Some((month, _)) = self.fixed_date() {
+            return month;
+        }
+        self.reference_date().map_or(1, |date| date.month())
+    }
+
+    /// Returns the date within `ORDERING_REFERENCE_YEAR` without calculating easter sunday.
+    fn reference_date(&self) -> Option<NaiveDate> {
+        match self.easter_offset() {
+            Some(offset) => {
+                let (month, day) = REFERENCE_EASTER_SUNDAY;
+                date(ORDERING_REFERENCE_YEAR, month, day).map(|d| d + Duration::days(offset))
+            }
+            None => self.date(ORDERING_REFERENCE_YEAR),
+        }
+    }
+
+    /// Returns the offset in days relative to easter sunday, e.g. `-2` for `Karfreitag`.
+    ///
+    /// `None` for holidays which do not depend on easter.
+    pub fn easter_offset(&self) -> Option<i64> {
+        match self {
+            Faschingsdienstag => Some(-47),
+            Aschermittwoch => Some(-46),
+            Gruendonnerstag => Some(-3),
+            Karfreitag => Some(-2),
+            Ostersonntag => Some(0),
+            Ostermontag => Some(1),
+            ChristiHimmelfahrt => Some(39),
+            Pfingstsonntag => Some(49),
+            Pfingstmontag => Some(50),
+            Fronleichnam => Some(60),
+            _ => None,
+        }
+    }
+
+    /// True if the date of the holiday changes from year to year.
+    ///
+    /// This applies to all holidays relative to easter sunday or to the fourth advent,
+    /// as well as to Buß- und Bettag, Muttertag and Erntedankfest.
+    pub fn is_movable(&self) -> bool {
+        matches!(
+            self,
+            Faschingsdienstag
+                | Aschermittwoch
+                | Gruendonnerstag
+                | Karfreitag
+                | Ostersonntag
+                | Ostermontag
+                | Muttertag
+                | ChristiHimmelfahrt
+                | Pfingstsonntag
+                | Pfingstmontag
+                | Fronleichnam
+                | Erntedankfest
+                | Volkstrauertag
+                | BussUndBettag
+                | Totensonntag
+                | ErsterAdvent
+                | ZweiterAdvent
+                | DritterAdvent
+                | VierterAdvent
+        )
+    }
+
+    /// True if the holiday is a public holiday in all regions of Germany.
+    ///
+    /// Reformationstag is `false`, although it was a nationwide public holiday in 2017 only.
+    pub fn is_nationwide_public(&self) -> bool {
+        BUNDESWEITE_FEIERTAGE.contains(self)
+    }
+
+    /// True if the holiday is a public holiday in at least one region in at least one year.
+    ///
+    /// Holidays which only apply to some communities, e.g. the Augsburger Friedensfest, count as well.
+    /// `false` for customary observances like Heiligabend or Silvester.
+    pub fn is_public_in_any_region(&self) -> bool {
+        match self {
+            Neujahr
+            | HeiligeDreiKoenige
+            | Frauentag
+            | Karfreitag
+            | Ostermontag
+            | ErsterMai
+            | TagDerBefreiung
+            | ChristiHimmelfahrt
+            | Pfingstmontag
+            | SiebzehnterJuni
+            | Fronleichnam
+            | AugsburgerFriedensfest
+            | MariaeHimmelfahrt
+            | Weltkindertag
+            | TagDerDeutschenEinheit
+            | Reformationstag
+            | Allerheiligen
+            | BussUndBettag
+            | ErsterWeihnachtsfeiertag
+            | ZweiterWeihnachtsfeiertag => true,
+            // Public holidays in Brandenburg and Hessen,
+            // but excluded by `GermanRegion` as they are always sundays.
+            Ostersonntag | Pfingstsonntag => true,
+            Faschingsdienstag | Aschermittwoch | Gruendonnerstag | Muttertag | Erntedankfest
+            | Volkstrauertag | Totensonntag | ErsterAdvent | ZweiterAdvent | DritterAdvent
+            | VierterAdvent | Nikolaus | Heiligabend | Silvester => false,
+        }
+    }
+
+    /// True for Heiligabend and Silvester, which are commonly treated as half days at work.
+    ///
+    /// This reflects workplace custom, not statutory law.
+    /// Neither is a public holiday, hence they are never listed by `GermanRegion`.
+    pub fn is_half_day(&self) -> bool {
+        matches!(self, Heiligabend | Silvester)
+    }
+
+    /// Classifies the holiday as christian, secular or other.
+    pub fn category(&self) -> HolidayCategory {
+        match self {
+            // Commemorations of the state, political or social movements.
+            // Volkstrauertag is a state day of remembrance despite falling into the church year.
+            Neujahr
+            | Frauentag
+            | ErsterMai
+            | TagDerBefreiung
+            | SiebzehnterJuni
+            | Weltkindertag
+            | TagDerDeutschenEinheit
+            | Volkstrauertag => HolidayCategory::Secular,
+            // Customs without a liturgical meaning of their own,
+            // although carnival is tied to the easter cycle.
+            Faschingsdienstag | Muttertag | Silvester => HolidayCategory::Other,
+            // The Augsburger Friedensfest is a protestant celebration of the Peace of Augsburg.
+            HeiligeDreiKoenige
+            | Aschermittwoch
+            | Gruendonnerstag
+            | Karfreitag
+            | Ostersonntag
+            | Ostermontag
+            | ChristiHimmelfahrt
+            | Pfingstsonntag
+            | Pfingstmontag
+            | Fronleichnam
+            | AugsburgerFriedensfest
+            | MariaeHimmelfahrt
+            | Erntedankfest
+            | Reformationstag
+            | Allerheiligen
+            | BussUndBettag
+            | Totensonntag
+            | ErsterAdvent
+            | ZweiterAdvent
+            | DritterAdvent
+            | VierterAdvent
+            | Nikolaus
+            | Heiligabend
+            | ErsterWeihnachtsfeiertag
+            | ZweiterWeihnachtsfeiertag => HolidayCategory::Christian,
+        }
+    }
+
+    /// Suggests an RGB color for rendering the holiday within calendars, based on its category.
+    ///
+    /// The palette is stable:
+    /// * christian holidays: muted purple `(128, 90, 160)`
+    /// * secular holidays: blue `(52, 101, 164)`
+    /// * other holidays: gray `(136, 138, 133)`
+    pub fn suggested_color(&self) -> (u8, u8, u8) {
+        match self.category() {
+            HolidayCategory::Christian => (128, 90, 160),
+            HolidayCategory::Secular => (52, 101, 164),
+            HolidayCategory::Other => (136, 138, 133),
+        }
+    }
+
     pub fn description(&self) -> &'static str {
         match self {
             Neujahr => "Neujahr",
@@ -87,6 +510,7 @@ impl GermanHoliday {
             Ostermontag => "Ostermontag",
             ErsterMai => "Erster Mai",
             TagDerBefreiung => "Tag der Befreiung",
+            Muttertag => "Muttertag",
             ChristiHimmelfahrt => "Christi Himmelfahrt",
             Pfingstsonntag => "Pfingstsonntag",
             Pfingstmontag => "Pfingstmontag",
@@ -96,61 +520,739 @@ impl GermanHoliday {
             MariaeHimmelfahrt => "Mariä Himmelfahrt",
             Weltkindertag => "Weltkindertag",
             TagDerDeutschenEinheit => "Tag der Deutschen Einheit",
+            Erntedankfest => "Erntedankfest",
             Reformationstag => "Reformationstag",
             Allerheiligen => "Allerheiligen",
+            Volkstrauertag => "Volkstrauertag",
             BussUndBettag => "Buß- und Bettag",
+            Totensonntag => "Totensonntag",
+            ErsterAdvent => "Erster Advent",
+            ZweiterAdvent => "Zweiter Advent",
+            DritterAdvent => "Dritter Advent",
+            VierterAdvent => "Vierter Advent",
+            Nikolaus => "Nikolaus",
             Heiligabend => "Heiligabend",
             ErsterWeihnachtsfeiertag => "Erster Weihnachtsfeiertag",
             ZweiterWeihnachtsfeiertag => "Zweiter Weihnachtsfeiertag",
             Silvester => "Silvester",
         }
     }
+
+    /// URL of the German Wikipedia article about the holiday.
+    ///
+    /// Holidays without an article of their own link to the article of their feast,
+    /// e.g. all Sundays of Advent link to the article about Advent.
+    pub fn wikipedia_url(&self) -> &'static str {
+        match self {
+            Neujahr => "https://de.wikipedia.org/wiki/Neujahr",
+            HeiligeDreiKoenige => "https://de.wikipedia.org/wiki/Erscheinung_des_Herrn",
+            Frauentag => "https://de.wikipedia.org/wiki/Internationaler_Frauentag",
+            Faschingsdienstag => "https://de.wikipedia.org/wiki/Fastnachtsdienstag",
+            Aschermittwoch => "https://de.wikipedia.org/wiki/Aschermittwoch",
+            Gruendonnerstag => "https://de.wikipedia.org/wiki/Gr%C3%BCndonnerstag",
+            Karfreitag => "https://de.wikipedia.org/wiki/Karfreitag",
+            Ostersonntag => "https://de.wikipedia.org/wiki/Ostern",
+            Ostermontag => "https://de.wikipedia.org/wiki/Ostermontag",
+            ErsterMai => "https://de.wikipedia.org/wiki/Erster_Mai",
+            TagDerBefreiung => "https://de.wikipedia.org/wiki/Tag_der_Befreiung",
+            Muttertag => "https://de.wikipedia.org/wiki/Muttertag",
+            ChristiHimmelfahrt => "https://de.wikipedia.org/wiki/Christi_Himmelfahrt",
+            Pfingstsonntag => "https://de.wikipedia.org/wiki/Pfingsten",
+            Pfingstmontag => "https://de.wikipedia.org/wiki/Pfingstmontag",
+            SiebzehnterJuni => "https://de.wikipedia.org/wiki/Volksaufstand_vom_17._Juni_1953",
+            Fronleichnam => "https://de.wikipedia.org/wiki/Fronleichnam",
+            AugsburgerFriedensfest => "https://de.wikipedia.org/wiki/Augsburger_Hohes_Friedensfest",
+            MariaeHimmelfahrt => "https://de.wikipedia.org/wiki/Mari%C3%A4_Himmelfahrt",
+            Weltkindertag => "https://de.wikipedia.org/wiki/Weltkindertag",
+            TagDerDeutschenEinheit => "https://de.wikipedia.org/wiki/Tag_der_Deutschen_Einheit",
+            Erntedankfest => "https://de.wikipedia.org/wiki/Erntedankfest",
+            Reformationstag => "https://de.wikipedia.org/wiki/Reformationstag",
+            Allerheiligen => "https://de.wikipedia.org/wiki/Allerheiligen",
+            Volkstrauertag => "https://de.wikipedia.org/wiki/Volkstrauertag",
+            BussUndBettag => "https://de.wikipedia.org/wiki/Bu%C3%9F-_und_Bettag",
+            Totensonntag => "https://de.wikipedia.org/wiki/Totensonntag",
+            ErsterAdvent => "https://de.wikipedia.org/wiki/Advent",
+            ZweiterAdvent => "https://de.wikipedia.org/wiki/Advent",
+            DritterAdvent => "https://de.wikipedia.org/wiki/Advent",
+            VierterAdvent => "https://de.wikipedia.org/wiki/Advent",
+            Nikolaus => "https://de.wikipedia.org/wiki/Nikolaustag",
+            Heiligabend => "https://de.wikipedia.org/wiki/Heiliger_Abend",
+            ErsterWeihnachtsfeiertag => "https://de.wikipedia.org/wiki/Weihnachten",
+            ZweiterWeihnachtsfeiertag => "https://de.wikipedia.org/wiki/Weihnachten",
+            Silvester => "https://de.wikipedia.org/wiki/Silvester",
+        }
+    }
+
+    /// Name of the holiday in the given language.
+    pub fn localized_name(&self, lang: Language) -> &'static str {
+        match lang {
+            Language::De => self.description(),
+            Language::En => self.english_name(),
+        }
+    }
+
+    /// Name of the enum variant, e.g. `"ChristiHimmelfahrt"`.
+    ///
+    /// Unlike `GermanHoliday::description`, the name is ASCII only and stable across versions,
+    /// which makes it suitable for keys and URLs.
+    pub fn variant_name(&self) -> &'static str {
+        match self {
+            Neujahr => "Neujahr",
+            HeiligeDreiKoenige => "HeiligeDreiKoenige",
+            Frauentag => "Frauentag",
+            Faschingsdienstag => "Faschingsdienstag",
+            Aschermittwoch => "Aschermittwoch",
+            Gruendonnerstag => "Gruendonnerstag",
+            Karfreitag => "Karfreitag",
+            Ostersonntag => "Ostersonntag",
+            Ostermontag => "Ostermontag",
+            ErsterMai => "ErsterMai",
+            TagDerBefreiung => "TagDerBefreiung",
+            Muttertag => "Muttertag",
+            ChristiHimmelfahrt => "ChristiHimmelfahrt",
+            Pfingstsonntag => "Pfingstsonntag",
+            Pfingstmontag => "Pfingstmontag",
+            SiebzehnterJuni => "SiebzehnterJuni",
+            Fronleichnam => "Fronleichnam",
+            AugsburgerFriedensfest => "AugsburgerFriedensfest",
+            MariaeHimmelfahrt => "MariaeHimmelfahrt",
+            Weltkindertag => "Weltkindertag",
+            TagDerDeutschenEinheit => "TagDerDeutschenEinheit",
+            Erntedankfest => "Erntedankfest",
+            Reformationstag => "Reformationstag",
+            Allerheiligen => "Allerheiligen",
+            Volkstrauertag => "Volkstrauertag",
+            BussUndBettag => "BussUndBettag",
+            Totensonntag => "Totensonntag",
+            ErsterAdvent => "ErsterAdvent",
+            ZweiterAdvent => "ZweiterAdvent",
+            DritterAdvent => "DritterAdvent",
+            VierterAdvent => "VierterAdvent",
+            Nikolaus => "Nikolaus",
+            Heiligabend => "Heiligabend",
+            ErsterWeihnachtsfeiertag => "ErsterWeihnachtsfeiertag",
+            ZweiterWeihnachtsfeiertag => "ZweiterWeihnachtsfeiertag",
+            Silvester => "Silvester",
+        }
+    }
+
+    /// Parses the name of the enum variant as returned by `GermanHoliday::variant_name`.
+    pub fn from_variant_name(s: &str) -> Option<GermanHoliday> {
+        match s {
+            "Neujahr" => Some(Neujahr),
+            "HeiligeDreiKoenige" => Some(HeiligeDreiKoenige),
+            "Frauentag" => Some(Frauentag),
+            "Faschingsdienstag" => Some(Faschingsdienstag),
+            "Aschermittwoch" => Some(Aschermittwoch),
+            "Gruendonnerstag" => Some(Gruendonnerstag),
+            "Karfreitag" => Some(Karfreitag),
+            "Ostersonntag" => Some(Ostersonntag),
+            "Ostermontag" => Some(Ostermontag),
+            "ErsterMai" => Some(ErsterMai),
+            "TagDerBefreiung" => Some(TagDerBefreiung),
+            "Muttertag" => Some(Muttertag),
+            "ChristiHimmelfahrt" => Some(ChristiHimmelfahrt),
+            "Pfingstsonntag" => Some(Pfingstsonntag),
+            "Pfingstmontag" => Some(Pfingstmontag),
+            "SiebzehnterJuni" => Some(SiebzehnterJuni),
+            "Fronleichnam" => Some(Fronleichnam),
+            "AugsburgerFriedensfest" => Some(AugsburgerFriedensfest),
+            "MariaeHimmelfahrt" => Some(MariaeHimmelfahrt),
+            "Weltkindertag" => Some(Weltkindertag),
+            "TagDerDeutschenEinheit" => Some(TagDerDeutschenEinheit),
+            "Erntedankfest" => Some(Erntedankfest),
+            "Reformationstag" => Some(Reformationstag),
+            "Allerheiligen" => Some(Allerheiligen),
+            "Volkstrauertag" => Some(Volkstrauertag),
+            "BussUndBettag" => Some(BussUndBettag),
+            "Totensonntag" => Some(Totensonntag),
+            "ErsterAdvent" => Some(ErsterAdvent),
+            "ZweiterAdvent" => Some(ZweiterAdvent),
+            "DritterAdvent" => Some(DritterAdvent),
+            "VierterAdvent" => Some(VierterAdvent),
+            "Nikolaus" => Some(Nikolaus),
+            "Heiligabend" => Some(Heiligabend),
+            "ErsterWeihnachtsfeiertag" => Some(ErsterWeihnachtsfeiertag),
+            "ZweiterWeihnachtsfeiertag" => Some(ZweiterWeihnachtsfeiertag),
+            "Silvester" => Some(Silvester),
+            _ => None,
+        }
+    }
+
+    /// Abbreviated German name of the holiday, e.g. `"Karfr."` for Karfreitag.
+    ///
+    /// Short names are at most 10 characters long, which suits narrow calendar cells.
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            Neujahr => "Neuj.",
+            HeiligeDreiKoenige => "Hl. 3 Kön.",
+            Frauentag => "Frauent.",
+            Faschingsdienstag => "Fasch.",
+            Aschermittwoch => "Aschermi.",
+            Gruendonnerstag => "Gründo.",
+            Karfreitag => "Karfr.",
+            Ostersonntag => "Osterso.",
+            Ostermontag => "Osterm.",
+            ErsterMai => "1. Mai",
+            TagDerBefreiung => "Befreiung",
+            Muttertag => "Muttert.",
+            ChristiHimmelfahrt => "Chr. Himm.",
+            Pfingstsonntag => "Pfingstso.",
+            Pfingstmontag => "Pfingstm.",
+            SiebzehnterJuni => "17. Juni",
+            Fronleichnam => "Fronl.",
+            AugsburgerFriedensfest => "Friedensf.",
+            MariaeHimmelfahrt => "Mariä H.",
+            Weltkindertag => "Weltkindt.",
+            TagDerDeutschenEinheit => "Dt. Einh.",
+            Erntedankfest => "Erntedank",
+            Reformationstag => "Reform.",
+            Allerheiligen => "Allerh.",
+            Volkstrauertag => "Volkstr.",
+            BussUndBettag => "Bußtag",
+            Totensonntag => "Totenso.",
+            ErsterAdvent => "1. Adv.",
+            ZweiterAdvent => "2. Adv.",
+            DritterAdvent => "3. Adv.",
+            VierterAdvent => "4. Adv.",
+            Nikolaus => "Nikolaus",
+            Heiligabend => "Heiligab.",
+            ErsterWeihnachtsfeiertag => "1. Weihn.",
+            ZweiterWeihnachtsfeiertag => "2. Weihn.",
+            Silvester => "Silvester",
+        }
+    }
+
+    /// English name of the holiday.
+    pub fn english_name(&self) -> &'static str {
+        match self {
+            Neujahr => "New Year's Day",
+            HeiligeDreiKoenige => "Epiphany",
+            Frauentag => "International Women's Day",
+            Faschingsdienstag => "Shrove Tuesday",
+            Aschermittwoch => "Ash Wednesday",
+            Gruendonnerstag => "Maundy Thursday",
+            Karfreitag => "Good Friday",
+            Ostersonntag => "Easter Sunday",
+            Ostermontag => "Easter Monday",
+            ErsterMai => "Labour Day",
+            TagDerBefreiung => "Liberation Day",
+            Muttertag => "Mother's Day",
+            ChristiHimmelfahrt => "Ascension Day",
+            Pfingstsonntag => "Whit Sunday",
+            Pfingstmontag => "Whit Monday",
+            SiebzehnterJuni => "June 17th",
+            Fronleichnam => "Corpus Christi",
+            AugsburgerFriedensfest => "Augsburg High Peace Festival",
+            MariaeHimmelfahrt => "Assumption Day",
+            Weltkindertag => "World Children's Day",
+            TagDerDeutschenEinheit => "Day of German Unity",
+            Erntedankfest => "Harvest Festival",
+            Reformationstag => "Reformation Day",
+            Allerheiligen => "All Saints' Day",
+            Volkstrauertag => "National Day of Mourning",
+            BussUndBettag => "Day of Repentance and Prayer",
+            Totensonntag => "Sunday of the Dead",
+            ErsterAdvent => "First Sunday of Advent",
+            ZweiterAdvent => "Second Sunday of Advent",
+            DritterAdvent => "Third Sunday of Advent",
+            VierterAdvent => "Fourth Sunday of Advent",
+            Nikolaus => "St. Nicholas Day",
+            Heiligabend => "Christmas Eve",
+            ErsterWeihnachtsfeiertag => "Christmas Day",
+            ZweiterWeihnachtsfeiertag => "Boxing Day",
+            Silvester => "New Year's Eve",
+        }
+    }
+}
+
+/// Non-leap year used to order holidays.
+const ORDERING_REFERENCE_YEAR: i32 = 2019;
+
+/// Easter sunday within `ORDERING_REFERENCE_YEAR`.
+const REFERENCE_EASTER_SUNDAY: (u32, u32) = (4, 21);
+
+/// Holidays are ordered by their date within a representative year (2019).
+///
+/// This is the calendar order for that year, but movable holidays might be
+/// ordered differently in other years, e.g. Fronleichnam and Siebzehnter Juni.
+/// Easter-based holidays are ordered by their offset from the known easter sunday of 2019,
+/// so comparisons never calculate easter.
+impl Ord for GermanHoliday {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.reference_date()
+            .cmp(&other.reference_date())
+            .then_with(|| (*self as u8).cmp(&(*other as u8)))
+    }
+}
+
+impl PartialOrd for GermanHoliday {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Converts the holiday into its German description, see `GermanHoliday::description`.
+impl From<GermanHoliday> for &'static str {
+    fn from(holiday: GermanHoliday) -> Self {
+        holiday.description()
+    }
 }
 
 fn bus_und_bettag(year: i32) -> Option<NaiveDate> {
-    let reference_date = NaiveDate::from_ymd(year, 11, 23);
+    let reference_date = NaiveDate::from_ymd_opt(year, 11, 23)?;
     let weekday_ordinal = i64::from(reference_date.weekday().num_days_from_monday());
-    let duration_to_previous_wednesday = if weekday_ordinal < 3 {
-        Duration::days(-(weekday_ordinal + 5))
+    let days_to_previous_wednesday = if weekday_ordinal < 3 {
+        -(weekday_ordinal + 5)
     } else {
-        Duration::days(2 - weekday_ordinal)
+        2 - weekday_ordinal
     };
-    Some(reference_date + duration_to_previous_wednesday)
+    add_days(reference_date, days_to_previous_wednesday)
+}
+
+/// The fourth advent is the last sunday before or on Heiligabend.
+fn relative_to_fourth_advent(year: i32, days_offset: i64) -> Option<NaiveDate> {
+    let heiligabend = NaiveDate::from_ymd_opt(year, 12, 24)?;
+    let days_since_sunday = i64::from(heiligabend.weekday().num_days_from_sunday());
+    add_days(heiligabend, days_offset.checked_sub(days_since_sunday)?)
 }
 
 fn date(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
     NaiveDate::from_ymd_opt(year, month, day)
 }
 
-fn relative_to_easter_sunday(year: i32, days_offset: i64) -> Option<NaiveDate> {
-    let easter_sunday = computus::gregorian(year).ok()?;
-    let date = NaiveDate::from_ymd_opt(easter_sunday.year, easter_sunday.month, easter_sunday.day)?;
-    Some(date + Duration::days(days_offset))
+/// Unlike adding a `Duration`, returns `None` instead of panicking if the result is out of range.
+fn add_days(date: NaiveDate, days: i64) -> Option<NaiveDate> {
+    let magnitude = Days::new(days.unsigned_abs());
+    if days < 0 {
+        date.checked_sub_days(magnitude)
+    } else {
+        date.checked_add_days(magnitude)
+    }
+}
+
+pub(crate) fn relative_to_easter_sunday(
+    year: i32,
+    days_offset: i64,
+    method: EasterMethod,
+) -> Option<NaiveDate> {
+    let date = match method {
+        EasterMethod::Gregorian => {
+            let easter_sunday = computus::gregorian(year).ok()?;
+            NaiveDate::from_ymd_opt(easter_sunday.year, easter_sunday.month, easter_sunday.day)?
+        }
+        EasterMethod::JulianOrthodox => {
+            let easter_sunday = computus::julian(year).ok()?;
+            let julian_date = NaiveDate::from_ymd_opt(
+                easter_sunday.year,
+                easter_sunday.month,
+                easter_sunday.day,
+            )?;
+            // difference between the Julian and the Gregorian calendar
+            add_days(julian_date, i64::from(year / 100 - year / 400 - 2))?
+        }
+    };
+    add_days(date, days_offset)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tests::ymd;
     use crate::DateExt;
-    use chrono::Weekday;
+    use crate::{GermanRegion, RegionOptions};
     use proptest::prelude::*;
+    use std::collections::HashSet;
 
     proptest! {
     #[test]
     fn test_bus_und_bettag_is_wed_before_23th_nov(y in 1i32..2999) {
         let date = bus_und_bettag(y).unwrap();
         assert_eq!(Weekday::Wed, date.weekday());
-        let duration = date.signed_duration_since(NaiveDate::from_ymd(y, 11, 23));
+        let duration = date.signed_duration_since(ymd(y, 11, 23));
         assert!(duration.num_days() <= -1);
         assert!(duration.num_days() >= -7);
     }
     }
 
+    proptest! {
+    #[test]
+    fn dates_do_not_panic(year: i32) {
+        assert_eq!(
+            NaiveDate::from_ymd_opt(year, 11, 23).is_some(),
+            bus_und_bettag(year).is_some()
+        );
+        for holiday in GermanHoliday::all() {
+            holiday.date(year);
+        }
+    }
+    }
+
     proptest! {
     #[test]
     fn relative_to_easter_sunday_does_not_panic(year: i32, offset: i64) {
-        relative_to_easter_sunday(year, offset);
+        relative_to_easter_sunday(year, offset, EasterMethod::Gregorian);
+        relative_to_easter_sunday(year, offset, EasterMethod::JulianOrthodox);
+        relative_to_easter_sunday(2019, offset, EasterMethod::Gregorian);
+        relative_to_easter_sunday(2019, offset, EasterMethod::JulianOrthodox);
+        relative_to_fourth_advent(2019, offset);
+    }
+    }
+
+    #[test]
+    fn english_names() {
+        assert_eq!("New Year's Day", Neujahr.english_name());
+        assert_eq!("Corpus Christi", Fronleichnam.english_name());
+        assert_eq!("Day of German Unity", TagDerDeutschenEinheit.english_name());
+    }
+
+    #[test]
+    fn all_holidays_are_distinct() {
+        let holidays: HashSet<GermanHoliday> = GermanHoliday::all().iter().cloned().collect();
+        assert_eq!(36, holidays.len());
+    }
+
+    #[test]
+    fn all_contains_every_variant_in_declaration_order() {
+        assert_eq!(Silvester as usize + 1, GermanHoliday::all().len());
+        for (index, holiday) in GermanHoliday::all().iter().enumerate() {
+            assert_eq!(index, *holiday as usize);
+            assert!(holiday.date(2019).is_some());
+            assert!(!holiday.description().is_empty());
+        }
+    }
+
+    #[test]
+    fn holidays_are_ordered_by_calendar() {
+        let mut holidays = GermanHoliday::all().to_vec();
+        holidays.sort();
+        assert_eq!(Some(&Neujahr), holidays.first());
+        assert_eq!(Some(&Silvester), holidays.last());
+        assert!(Frauentag < Karfreitag);
+        assert!(Karfreitag < Ostermontag);
+        assert!(Pfingstmontag < Fronleichnam);
+        assert!(BussUndBettag < Heiligabend);
+        assert!(holidays
+            .windows(2)
+            .all(|w| w[0].date(2019) <= w[1].date(2019)));
+    }
+
+    proptest! {
+    #[test]
+    fn karfreitag_is_always_friday(year in 1583i32..9999) {
+        assert_eq!(Some(Weekday::Fri), Karfreitag.weekday(year));
+    }
+    }
+
+    #[test]
+    fn fixed_dates() {
+        assert_eq!(Some((5, 1)), ErsterMai.fixed_date());
+        assert_eq!(None, Karfreitag.fixed_date());
+        for holiday in GermanHoliday::all() {
+            assert_eq!(holiday.is_movable(), holiday.fixed_date().is_none());
+            if let Some((month, day)) = holiday.fixed_date() {
+                assert_eq!(date(2019, month, day), holiday.date(2019));
+            }
+        }
+    }
+
+    #[test]
+    fn nationwide_public_holidays() {
+        assert!(TagDerDeutschenEinheit.is_nationwide_public());
+        assert!(!Fronleichnam.is_nationwide_public());
+        assert!(!Reformationstag.is_nationwide_public());
+        assert_eq!(
+            9,
+            GermanHoliday::all()
+                .iter()
+                .filter(|holiday| holiday.is_nationwide_public())
+                .count()
+        );
+    }
+
+    #[test]
+    fn public_in_any_region() {
+        assert!(Fronleichnam.is_public_in_any_region());
+        assert!(!Silvester.is_public_in_any_region());
+        assert!(!Heiligabend.is_public_in_any_region());
+        assert!(!Aschermittwoch.is_public_in_any_region());
+        let options = RegionOptions::default()
+            .with_sorbian_communities()
+            .with_augsburg();
+        for region in GermanRegion::all() {
+            for year in 1995..2030 {
+                for holiday in region.holidays_in_year_with_options(year, options) {
+                    assert!(holiday.is_public_in_any_region());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn wikipedia_urls() {
+        assert!(Reformationstag.wikipedia_url().contains("Reformationstag"));
+        assert_eq!(
+            "https://de.wikipedia.org/wiki/Bu%C3%9F-_und_Bettag",
+            BussUndBettag.wikipedia_url()
+        );
+        for holiday in GermanHoliday::all() {
+            assert!(holiday
+                .wikipedia_url()
+                .starts_with("https://de.wikipedia.org/wiki/"));
+        }
+    }
+
+    #[test]
+    fn from_fixed_date_is_inverse_of_fixed_date() {
+        for holiday in GermanHoliday::all() {
+            if let Some((month, day)) = holiday.fixed_date() {
+                assert_eq!(Some(*holiday), GermanHoliday::from_fixed_date(month, day));
+            }
+        }
+        assert_eq!(None, GermanHoliday::from_fixed_date(4, 19));
+    }
+
+    #[test]
+    fn ostermontag_dates_in_range() {
+        assert_eq!(
+            vec![
+                (2015, ymd(2015, 4, 6)),
+                (2016, ymd(2016, 3, 28)),
+                (2017, ymd(2017, 4, 17)),
+                (2018, ymd(2018, 4, 2)),
+                (2019, ymd(2019, 4, 22)),
+                (2020, ymd(2020, 4, 13)),
+            ],
+            Ostermontag.dates_in_range(2015..=2020)
+        );
+        assert_eq!(
+            vec![(9999, ymd(9999, 3, 26))],
+            Karfreitag.dates_in_range(9999..=10005)
+        );
+    }
+
+    #[test]
+    fn variant_names_round_trip() {
+        assert_eq!("ChristiHimmelfahrt", ChristiHimmelfahrt.variant_name());
+        for holiday in GermanHoliday::all() {
+            assert!(holiday.variant_name().is_ascii());
+            assert_eq!(
+                Some(*holiday),
+                GermanHoliday::from_variant_name(holiday.variant_name())
+            );
+            assert_eq!(format!("{:?}", holiday), holiday.variant_name());
+        }
+        assert_eq!(
+            None,
+            GermanHoliday::from_variant_name("Christi Himmelfahrt")
+        );
+    }
+
+    #[test]
+    fn suggested_colors_by_category() {
+        let christian = Karfreitag.suggested_color();
+        for holiday in GermanHoliday::all() {
+            if holiday.category() == HolidayCategory::Christian {
+                assert_eq!(christian, holiday.suggested_color());
+            } else {
+                assert_ne!(christian, holiday.suggested_color());
+            }
+        }
+        assert_eq!((52, 101, 164), TagDerDeutschenEinheit.suggested_color());
+        assert_ne!(Silvester.suggested_color(), Neujahr.suggested_color());
+    }
+
+    #[test]
+    fn observed_dates() {
+        // Neujahr 2022 is a saturday, Erster Weihnachtsfeiertag 2022 a sunday.
+        assert_eq!(
+            Some(ymd(2021, 12, 31)),
+            Neujahr.observed_date(2022, SubstitutionRule::NearestWeekday)
+        );
+        assert_eq!(
+            Some(ymd(2022, 1, 1)),
+            Neujahr.observed_date(2022, SubstitutionRule::None)
+        );
+        assert_eq!(
+            Some(ymd(2022, 12, 26)),
+            ErsterWeihnachtsfeiertag.observed_date(2022, SubstitutionRule::NearestWeekday)
+        );
+        assert_eq!(
+            Some(ymd(2019, 1, 1)),
+            Neujahr.observed_date(2019, SubstitutionRule::NearestWeekday)
+        );
+        assert_eq!(
+            Karfreitag.date(2019),
+            Karfreitag.observed_date(2019, SubstitutionRule::default())
+        );
+    }
+
+    #[test]
+    fn localized_names() {
+        let german = Fronleichnam.localized_name(Language::De);
+        let english = Fronleichnam.localized_name(Language::En);
+        assert_eq!("Fronleichnam", german);
+        assert_eq!("Corpus Christi", english);
+        assert_ne!(german, english);
+        for holiday in GermanHoliday::all() {
+            assert_eq!(
+                holiday.description(),
+                holiday.localized_name(Language::default())
+            );
+            assert!(!holiday.localized_name(Language::En).is_empty());
+        }
+    }
+
+    #[test]
+    fn short_names() {
+        assert_eq!("Neuj.", Neujahr.short_name());
+        assert_eq!("1. Weihn.", ErsterWeihnachtsfeiertag.short_name());
+        let short_names: HashSet<&str> = GermanHoliday::all()
+            .iter()
+            .map(|holiday| holiday.short_name())
+            .collect();
+        assert_eq!(GermanHoliday::all().len(), short_names.len());
+        for short_name in short_names {
+            assert!(!short_name.is_empty());
+            assert!(short_name.chars().count() <= 10);
+        }
+    }
+
+    #[test]
+    fn into_str() {
+        let description: &str = BussUndBettag.into();
+        assert_eq!("Buß- und Bettag", description);
+        fn len(name: impl Into<&'static str>) -> usize {
+            name.into().len()
+        }
+        assert_eq!("Neujahr".len(), len(Neujahr));
+    }
+
+    #[test]
+    fn half_days() {
+        assert!(Heiligabend.is_half_day());
+        assert!(Silvester.is_half_day());
+        assert!(!ErsterWeihnachtsfeiertag.is_half_day());
+        assert!(!Neujahr.is_half_day());
     }
+
+    #[test]
+    fn orthodox_easter() {
+        let orthodox = |holiday: GermanHoliday, year| {
+            holiday.date_with_easter(year, EasterMethod::JulianOrthodox)
+        };
+        assert_eq!(date(2019, 4, 28), orthodox(Ostersonntag, 2019));
+        assert_eq!(date(2019, 4, 26), orthodox(Karfreitag, 2019));
+        assert_eq!(date(2021, 5, 2), orthodox(Ostersonntag, 2021));
+        assert_eq!(Ostersonntag.date(2017), orthodox(Ostersonntag, 2017));
+        assert_eq!(Neujahr.date(2019), orthodox(Neujahr, 2019));
+        assert_eq!(
+            Ostersonntag.date(2019),
+            Ostersonntag.date_with_easter(2019, EasterMethod::default())
+        );
+    }
+
+    #[test]
+    fn easter_offsets() {
+        assert_eq!(Some(-2), Karfreitag.easter_offset());
+        assert_eq!(Some(39), ChristiHimmelfahrt.easter_offset());
+        assert_eq!(Some(60), Fronleichnam.easter_offset());
+        assert_eq!(None, BussUndBettag.easter_offset());
+        assert_eq!(None, Neujahr.easter_offset());
+        for holiday in GermanHoliday::all() {
+            if let Some(offset) = holiday.easter_offset() {
+                assert_eq!(
+                    relative_to_easter_sunday(2019, offset, EasterMethod::Gregorian),
+                    holiday.date(2019)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn approx_months() {
+        assert_eq!(1, Neujahr.approx_month());
+        assert!([5, 6].contains(&Fronleichnam.approx_month()));
+        assert_eq!(11, BussUndBettag.approx_month());
+        assert_eq!(
+            Some(REFERENCE_EASTER_SUNDAY),
+            Ostersonntag
+                .date(ORDERING_REFERENCE_YEAR)
+                .map(|date| (date.month(), date.day()))
+        );
+        for holiday in GermanHoliday::all() {
+            assert_eq!(
+                holiday
+                    .date(ORDERING_REFERENCE_YEAR)
+                    .map(|date| date.month()),
+                Some(holiday.approx_month())
+            );
+        }
+    }
+
+    #[test]
+    fn date_or_panic_matches_date() {
+        for holiday in GermanHoliday::all() {
+            assert_eq!(holiday.date(2019).unwrap(), holiday.date_or_panic(2019));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot calculate date of Karfreitag in year 10000")]
+    fn date_or_panic_for_uncomputable_year() {
+        Karfreitag.date_or_panic(10000);
+    }
+
+    #[test]
+    fn matches_date() {
+        let date = ymd(2019, 4, 19);
+        assert!(Karfreitag.matches(date));
+        assert!(!Ostermontag.matches(date));
+        for holiday in GermanHoliday::all() {
+            assert_eq!(date.is_holiday(*holiday), holiday.matches(date));
+        }
+    }
+
+    #[test]
+    fn movable_holidays() {
+        assert!(Karfreitag.is_movable());
+        assert!(BussUndBettag.is_movable());
+        assert!(!Neujahr.is_movable());
+        assert!(!TagDerDeutschenEinheit.is_movable());
+    }
+
+    #[test]
+    fn holiday_from_date() {
+        assert_eq!(
+            Some(Heiligabend),
+            GermanHoliday::from_date(ymd(2019, 12, 24))
+        );
+        assert_eq!(
+            Some(Aschermittwoch),
+            GermanHoliday::from_date(ymd(2019, 3, 6))
+        );
+        assert_eq!(Some(ErsterMai), GermanHoliday::from_date(ymd(2008, 5, 1)));
+        assert_eq!(None, GermanHoliday::from_date(ymd(2019, 12, 23)));
+    }
+
+    #[test]
+    fn holiday_categories() {
+        assert_eq!(HolidayCategory::Christian, Karfreitag.category());
+        assert_eq!(HolidayCategory::Christian, Fronleichnam.category());
+        assert_eq!(HolidayCategory::Christian, Allerheiligen.category());
+        assert_eq!(HolidayCategory::Secular, TagDerDeutschenEinheit.category());
+        assert_eq!(HolidayCategory::Secular, ErsterMai.category());
+        assert_eq!(HolidayCategory::Secular, Frauentag.category());
+        assert_eq!(HolidayCategory::Other, Silvester.category());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let json = serde_json::to_string(&ChristiHimmelfahrt).unwrap();
+        assert_eq!("\"ChristiHimmelfahrt\"", json);
+        let holiday: GermanHoliday = serde_json::from_str(&json).unwrap();
+        assert_eq!(ChristiHimmelfahrt, holiday);
     }
 
     macro_rules! holiday_tests {
@@ -160,7 +1262,7 @@ mod tests {
         fn $name() {
             let holiday: GermanHoliday = $holiday;
             let (year, month, day) = $date;
-            let date = NaiveDate::from_ymd(year, month, day);
+            let date = ymd(year, month, day);
             assert!(date.is_holiday(holiday));
         }
     )*
@@ -184,6 +1286,9 @@ mod tests {
 
         ostermontag: Ostermontag, (2019, 4, 22),
         erstermai: ErsterMai, (2019, 5, 1),
+        muttertag1: Muttertag, (2019, 5, 12),
+        muttertag2: Muttertag, (2020, 5, 10),
+        muttertag3: Muttertag, (2021, 5, 9),
         christi_himmelfahrt: ChristiHimmelfahrt, (2019, 5, 30),
         pfingstsonntag: Pfingstsonntag, (2019, 6, 9),
         pfingstmontag: Pfingstmontag, (2019, 6, 10),
@@ -192,6 +1297,9 @@ mod tests {
         mariae_himmelfahrt: MariaeHimmelfahrt, (2019, 8, 15),
         weltkindertag: Weltkindertag, (2019, 9, 20),
         deutsche_einheit: TagDerDeutschenEinheit, (2019, 10, 3),
+        erntedankfest1: Erntedankfest, (2019, 10, 6),
+        erntedankfest2: Erntedankfest, (2017, 10, 1),
+        erntedankfest3: Erntedankfest, (2022, 10, 2),
         reformationstag: Reformationstag, (2019, 10, 31),
         allerheiligen: Allerheiligen, (2019, 11, 1),
 
@@ -202,10 +1310,18 @@ mod tests {
         bus_und_bettag5: BussUndBettag, (2022, 11, 16),
         bus_und_bettag6: BussUndBettag, (2023, 11, 22),
 
+        volkstrauertag: Volkstrauertag, (2019, 11, 17),
+        totensonntag: Totensonntag, (2019, 11, 24),
+        erster_advent: ErsterAdvent, (2019, 12, 1),
+        zweiter_advent: ZweiterAdvent, (2019, 12, 8),
+        dritter_advent: DritterAdvent, (2019, 12, 15),
+        vierter_advent1: VierterAdvent, (2019, 12, 22),
+        vierter_advent2: VierterAdvent, (2017, 12, 24),
+        nikolaus: Nikolaus, (2019, 12, 6),
+
         heiligabend: Heiligabend, (2019, 12, 24),
         erster_weihnachtsfeiertag: ErsterWeihnachtsfeiertag, (2019, 12, 25),
         zweiter_weihnachtsfeiertag: ZweiterWeihnachtsfeiertag, (2019, 12, 26),
         silvester: Silvester, (2019, 12, 31),
     }
-
 }
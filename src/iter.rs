@@ -0,0 +1,99 @@
+use crate::{GermanHoliday, GermanRegion};
+use chrono::NaiveDate;
+
+/// Provides holiday adaptors for iterators over dates.
+pub trait HolidayIteratorExt: Iterator<Item = NaiveDate> + Sized {
+    /// Yields only the dates which are public holidays within the specified region,
+    /// paired with the respective holiday.
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use holiday_de::{GermanHoliday, GermanRegion, HolidayIteratorExt};
+    ///
+    /// let start = NaiveDate::from_ymd_opt(2019, 12, 20).unwrap();
+    /// let holidays: Vec<_> = start
+    ///     .iter_days()
+    ///     .take(10)
+    ///     .filter_holidays(GermanRegion::Bayern)
+    ///     .map(|(_, holiday)| holiday)
+    ///     .collect();
+    /// assert_eq!(
+    ///     vec![
+    ///         GermanHoliday::ErsterWeihnachtsfeiertag,
+    ///         GermanHoliday::ZweiterWeihnachtsfeiertag,
+    ///     ],
+    ///     holidays
+    /// );
+    /// ```
+    fn filter_holidays(self, region: GermanRegion) -> FilterHolidays<Self> {
+        FilterHolidays { iter: self, region }
+    }
+}
+
+impl<I: Iterator<Item = NaiveDate>> HolidayIteratorExt for I {}
+
+/// Iterator returned by `HolidayIteratorExt::filter_holidays`.
+#[derive(Clone, Debug)]
+pub struct FilterHolidays<I> {
+    iter: I,
+    region: GermanRegion,
+}
+
+impl<I: Iterator<Item = NaiveDate>> Iterator for FilterHolidays<I> {
+    type Item = (NaiveDate, GermanHoliday);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let region = self.region;
+        self.iter.by_ref().find_map(|date| {
+            region
+                .holiday_from_date(date)
+                .map(|holiday| (date, holiday))
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::ymd;
+    use crate::GermanHoliday::*;
+    use crate::GermanRegion::*;
+
+    #[test]
+    fn filter_holidays_of_dates() {
+        let dates = vec![
+            ymd(2019, 1, 1),
+            ymd(2019, 1, 2),
+            ymd(2019, 1, 6),
+            ymd(2019, 3, 8),
+            ymd(2019, 10, 3),
+        ];
+        assert_eq!(
+            vec![
+                (ymd(2019, 1, 1), Neujahr),
+                (ymd(2019, 1, 6), HeiligeDreiKoenige),
+                (ymd(2019, 10, 3), TagDerDeutschenEinheit),
+            ],
+            dates
+                .iter()
+                .copied()
+                .filter_holidays(Bayern)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![
+                (ymd(2019, 1, 1), Neujahr),
+                (ymd(2019, 3, 8), Frauentag),
+                (ymd(2019, 10, 3), TagDerDeutschenEinheit),
+            ],
+            dates
+                .into_iter()
+                .filter_holidays(Berlin)
+                .collect::<Vec<_>>()
+        );
+    }
+}
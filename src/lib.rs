@@ -6,39 +6,288 @@
 //! see `GermanRegion` for more details.
 //! A comprehensive overview can be found within the German Wikipedia
 //! [Gesetzliche Feiertage in Deutschland](https://de.wikipedia.org/wiki/Gesetzliche_Feiertage_in_Deutschland).
-use chrono::{Datelike, NaiveDate};
+//!
+//! # Features
+//! * `std` (default): Implements `std::error::Error` for `UnsupportedYear`
+//!   and provides functions based on the local system clock, e.g. `GermanRegion::upcoming_holidays`.
+//!   Without it, the crate is `no_std` and only requires `alloc`.
+//!   Note that the `computus` dependency itself is not marked as `no_std`.
+//! * `serde`: Implements `Serialize` and `Deserialize` for `GermanHoliday` and `GermanRegion`.
+//! * `timezone`: Provides holidays as `DateTime` in Europe/Berlin via `chrono-tz`.
+//! * `time`: Implements `DateExt` for `time::Date`.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone};
+
+mod calendar;
+mod error;
+mod export;
+mod holiday_set;
 mod holidays;
+mod iter;
+mod overview;
+mod region_set;
 mod regions;
+pub mod util;
 
-pub use holidays::GermanHoliday;
-pub use regions::GermanRegion;
+pub use calendar::HolidayCalendar;
+pub use error::{InvalidRegionIndex, UnsupportedYear};
+pub use holiday_set::{HolidaySet, HolidaySetIter};
+pub use holidays::{EasterMethod, GermanHoliday, HolidayCategory, Language, SubstitutionRule};
+pub use iter::{FilterHolidays, HolidayIteratorExt};
+pub use overview::{
+    fraction_observing, is_public_holiday_anywhere, nationwide_overview, observance_matrix,
+    region_groups_by_holidays,
+};
+pub use region_set::{RegionSet, RegionSetIter};
+pub use regions::{GermanRegion, HolidayOverride, RegionOptions};
+pub use util::easter_sunday;
 
 /// Provides convenience methods for datelike data structures like `NaiveDate`.
+///
+/// Implementors only need to provide `DateExt::naive_date`.
 pub trait DateExt {
+    /// Returns the calendar date on which all other methods are based.
+    ///
+    /// `None` if the date cannot be represented by `NaiveDate`, in which case it is never a holiday.
+    fn naive_date(&self) -> Option<NaiveDate>;
+
     /// True if date is a holiday within the specified region.
     ///
     /// Always `false` for dates before 1995.
-    fn is_public_holiday_in(&self, region: GermanRegion) -> bool;
+    fn is_public_holiday_in(&self, region: GermanRegion) -> bool {
+        self.naive_date()
+            .is_some_and(|date| region.is_holiday(date))
+    }
 
     /// Returns the holiday if given date is a public holiday.
     ///
     /// Always `None` for dates before 1995.
-    fn public_holiday_in(&self, region: GermanRegion) -> Option<GermanHoliday>;
+    fn public_holiday_in(&self, region: GermanRegion) -> Option<GermanHoliday> {
+        self.naive_date()
+            .and_then(|date| region.holiday_from_date(date))
+    }
 
     /// True if date falls on the date of the given holiday.
-    fn is_holiday(&self, holiday: GermanHoliday) -> bool;
+    fn is_holiday(&self, holiday: GermanHoliday) -> bool {
+        self.naive_date().is_some_and(|date| holiday.matches(date))
+    }
+
+    /// True if date falls on the date of the given holiday in the given reference year.
+    ///
+    /// Unlike `DateExt::is_holiday`, the year of the date itself is not considered.
+    fn is_holiday_for_year(&self, holiday: GermanHoliday, year: i32) -> bool {
+        self.naive_date()
+            .is_some_and(|date| holiday.date(year) == Some(date))
+    }
+
+    /// Returns the number of days until the next public holiday within the specified region.
+    ///
+    /// `0` if the date itself is a public holiday.
+    fn days_until_next_public_holiday_in(&self, region: GermanRegion) -> Option<i64> {
+        let date = self.naive_date()?;
+        let (holiday_date, _) = region.next_holiday(date)?;
+        Some(holiday_date.signed_duration_since(date).num_days())
+    }
+
+    /// True if date is a public holiday within the specified region or otherwise free of school.
+    ///
+    /// Currently the only exception is Buß- und Bettag in Bayern,
+    /// which is no public holiday but free of school by law.
+    /// Regular school vacations are not covered.
+    fn is_school_holiday_in(&self, region: GermanRegion) -> bool {
+        self.is_public_holiday_in(region)
+            || (region == GermanRegion::Bayern && self.is_holiday(GermanHoliday::BussUndBettag))
+    }
+
+    /// Returns the holiday if given date is a public holiday within any of the specified regions.
+    ///
+    /// Regions are checked in the given order and the first match wins.
+    fn public_holiday_in_any(&self, regions: &[GermanRegion]) -> Option<GermanHoliday> {
+        regions
+            .iter()
+            .find_map(|region| self.public_holiday_in(*region))
+    }
+
+    /// Returns the number of consecutive days starting at the date which are either
+    /// weekends or public holidays within the specified region.
+    ///
+    /// `0` if the date itself is a working day.
+    fn consecutive_free_days_in(&self, region: GermanRegion) -> u32 {
+        let mut count = 0;
+        let mut date = self.naive_date();
+        while let Some(free_day) = date.filter(|date| !region.is_working_day(*date)) {
+            count += 1;
+            date = free_day.succ_opt();
+        }
+        count
+    }
 }
 
 impl DateExt for NaiveDate {
-    fn is_public_holiday_in(&self, region: GermanRegion) -> bool {
-        region.is_holiday(*self)
+    fn naive_date(&self) -> Option<NaiveDate> {
+        Some(*self)
     }
-    fn public_holiday_in(&self, region: GermanRegion) -> Option<GermanHoliday> {
-        region.holiday_from_date(*self)
+}
+
+/// Ignores the time of the `NaiveDateTime`.
+impl DateExt for NaiveDateTime {
+    fn naive_date(&self) -> Option<NaiveDate> {
+        Some(self.date())
     }
-    fn is_holiday(&self, holiday: GermanHoliday) -> bool {
-        let holiday_date = holiday.date(self.year());
-        Some(*self) == holiday_date
+}
+
+/// Uses the local date of the `DateTime` within its own time zone.
+impl<Tz: TimeZone> DateExt for DateTime<Tz> {
+    fn naive_date(&self) -> Option<NaiveDate> {
+        Some(self.date_naive())
+    }
+}
+
+/// Uses the calendar date of the `time::Date`.
+///
+/// Dates beyond the range of chrono, which exist with the `large-dates` feature of time,
+/// are never holidays.
+#[cfg(feature = "time")]
+impl DateExt for time::Date {
+    fn naive_date(&self) -> Option<NaiveDate> {
+        let (year, month, day) = self.to_calendar_date();
+        NaiveDate::from_ymd_opt(year, u32::from(u8::from(month)), u32::from(day))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GermanHoliday::*;
+    use crate::GermanRegion::*;
+    use chrono::{FixedOffset, Utc};
+
+    /// Shorthand for the valid dates used throughout the tests.
+    pub(crate) fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn implementors_only_provide_the_date() {
+        struct Day(Option<NaiveDate>);
+        impl DateExt for Day {
+            fn naive_date(&self) -> Option<NaiveDate> {
+                self.0
+            }
+        }
+        let weihnachten = Day(Some(ymd(2019, 12, 25)));
+        assert_eq!(
+            Some(ErsterWeihnachtsfeiertag),
+            weihnachten.public_holiday_in(Berlin)
+        );
+        assert_eq!(2, weihnachten.consecutive_free_days_in(Berlin));
+        let unrepresentable = Day(None);
+        assert!(!unrepresentable.is_public_holiday_in(Berlin));
+        assert_eq!(
+            None,
+            unrepresentable.days_until_next_public_holiday_in(Berlin)
+        );
+        assert_eq!(0, unrepresentable.consecutive_free_days_in(Berlin));
+    }
+
+    #[test]
+    fn date_time_uses_own_time_zone() {
+        let new_york = FixedOffset::west_opt(5 * 3600).unwrap();
+        let christmas_evening = new_york.with_ymd_and_hms(2019, 12, 25, 20, 0, 0).unwrap();
+        assert!(christmas_evening.is_public_holiday_in(Bayern));
+        assert!(christmas_evening.is_holiday(ErsterWeihnachtsfeiertag));
+
+        let late_evening = new_york.with_ymd_and_hms(2019, 12, 26, 23, 0, 0).unwrap();
+        assert_eq!(
+            Some(ZweiterWeihnachtsfeiertag),
+            late_evening.public_holiday_in(Bayern)
+        );
+        assert_eq!(
+            None,
+            late_evening.with_timezone(&Utc).public_holiday_in(Bayern)
+        );
+    }
+
+    #[test]
+    fn naive_date_time_ignores_time() {
+        let date_time = ymd(2019, 1, 1).and_hms_opt(23, 59, 0).unwrap();
+        assert!(date_time.is_public_holiday_in(Bayern));
+        assert_eq!(Some(Neujahr), date_time.public_holiday_in(Bayern));
+        assert!(date_time.is_holiday(Neujahr));
+    }
+
+    #[test]
+    fn days_until_next_public_holiday() {
+        assert_eq!(
+            Some(1),
+            ymd(2019, 12, 24).days_until_next_public_holiday_in(Berlin)
+        );
+        assert_eq!(
+            Some(0),
+            ymd(2019, 12, 25).days_until_next_public_holiday_in(Berlin)
+        );
+        assert_eq!(
+            Some(5),
+            ymd(2019, 12, 27).days_until_next_public_holiday_in(Berlin)
+        );
+    }
+
+    #[test]
+    fn bus_und_bettag_is_school_holiday_in_bayern() {
+        let bus_und_bettag = ymd(2019, 11, 20);
+        assert!(bus_und_bettag.is_school_holiday_in(Bayern));
+        assert!(!bus_und_bettag.is_public_holiday_in(Bayern));
+        assert!(!bus_und_bettag.is_school_holiday_in(Berlin));
+        assert!(bus_und_bettag.is_school_holiday_in(Sachsen));
+        let neujahr = ymd(2019, 1, 1);
+        assert!(neujahr.is_school_holiday_in(Berlin));
+    }
+
+    #[test]
+    fn public_holiday_in_any_region() {
+        let frauentag = ymd(2019, 3, 8);
+        assert_eq!(
+            Some(Frauentag),
+            frauentag.public_holiday_in_any(&[Bayern, Berlin])
+        );
+        assert_eq!(None, frauentag.public_holiday_in_any(&[Bayern, Hessen]));
+        assert_eq!(None, frauentag.public_holiday_in_any(&[]));
+    }
+
+    #[test]
+    fn consecutive_free_days() {
+        assert_eq!(4, ymd(2019, 4, 19).consecutive_free_days_in(Bayern));
+        assert_eq!(3, ymd(2019, 4, 20).consecutive_free_days_in(Bayern));
+        assert_eq!(2, ymd(2019, 12, 25).consecutive_free_days_in(Bayern));
+        assert_eq!(0, ymd(2019, 4, 18).consecutive_free_days_in(Bayern));
+        assert_eq!(1, ymd(1900, 1, 7).consecutive_free_days_in(Bayern));
+    }
+
+    #[test]
+    fn holiday_for_explicit_year() {
+        let silvester = ymd(2019, 12, 31);
+        assert!(silvester.is_holiday_for_year(Silvester, 2019));
+        assert!(!silvester.is_holiday_for_year(Silvester, 2020));
+        let neujahr = ymd(2020, 1, 1);
+        assert!(!neujahr.is_holiday_for_year(Neujahr, 2019));
+        assert!(neujahr.is_holiday_for_year(Neujahr, 2020));
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_date() {
+        let neujahr = time::Date::from_calendar_date(2019, time::Month::January, 1).unwrap();
+        assert!(neujahr.is_public_holiday_in(Bayern));
+        assert_eq!(Some(Neujahr), neujahr.public_holiday_in(Bayern));
+        let second = time::Date::from_calendar_date(2019, time::Month::January, 2).unwrap();
+        assert!(!second.is_public_holiday_in(Bayern));
+        assert_eq!(
+            Some(1),
+            time::Date::MAX.days_until_next_public_holiday_in(Bayern)
+        );
+        assert!(!time::Date::MIN.is_public_holiday_in(Bayern));
     }
 }
@@ -0,0 +1,168 @@
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+use chrono::NaiveDate;
+
+use crate::holidays::GermanHoliday;
+use crate::regions::GermanRegion;
+
+/// Returns each pair of region and holiday, for which the holiday is a public holiday
+/// within the region in the given year.
+///
+/// For years before 1995 this list will be empty.
+pub fn observance_matrix(year: i32) -> Vec<(GermanRegion, GermanHoliday)> {
+    GermanRegion::all()
+        .iter()
+        .flat_map(|region| {
+            region
+                .iter_holidays_in_year(year)
+                .map(move |holiday| (*region, holiday))
+        })
+        .collect()
+}
+
+/// True if the date is a public holiday in at least one region.
+///
+/// Always `false` for dates before 1995.
+pub fn is_public_holiday_anywhere(date: NaiveDate) -> bool {
+    GermanRegion::all()
+        .iter()
+        .any(|region| region.is_holiday(date))
+}
+
+/// Returns every date which is a public holiday in at least one region in the given year,
+/// together with all regions observing it.
+///
+/// Regions are listed in declaration order. For years before 1995 the map will be empty.
+pub fn nationwide_overview(year: i32) -> BTreeMap<NaiveDate, Vec<(GermanRegion, GermanHoliday)>> {
+    let mut overview: BTreeMap<NaiveDate, Vec<(GermanRegion, GermanHoliday)>> = BTreeMap::new();
+    for region in GermanRegion::all() {
+        for (date, holiday) in region.holiday_dates_in_year(year) {
+            overview.entry(date).or_default().push((*region, holiday));
+        }
+    }
+    overview
+}
+
+/// Partitions all regions into groups with the same public holidays in the given year.
+///
+/// Groups are ordered by their first region and regions within a group keep declaration order.
+/// For years before 1995 all regions form a single group.
+pub fn region_groups_by_holidays(year: i32) -> Vec<Vec<GermanRegion>> {
+    let mut groups: Vec<(Vec<GermanHoliday>, Vec<GermanRegion>)> = Vec::new();
+    for region in GermanRegion::all() {
+        let mut holidays = region.holidays_in_year(year);
+        holidays.sort();
+        match groups.iter_mut().find(|(group, _)| *group == holidays) {
+            Some((_, regions)) => regions.push(*region),
+            None => groups.push((holidays, vec![*region])),
+        }
+    }
+    groups.into_iter().map(|(_, regions)| regions).collect()
+}
+
+/// Returns the share of the population living in regions, in which the holiday
+/// is a public holiday in the given year, see `GermanRegion::population`.
+///
+/// Holidays applying only to some communities within a region, e.g. Fronleichnam in Sachsen,
+/// are not taken into account. Always `0.0` for years before 1995.
+pub fn fraction_observing(holiday: GermanHoliday, year: i32) -> f64 {
+    let total: u64 = GermanRegion::all()
+        .iter()
+        .map(GermanRegion::population)
+        .sum();
+    let observing: u64 = GermanRegion::all()
+        .iter()
+        .filter(|region| region.observes(holiday, year))
+        .map(GermanRegion::population)
+        .sum();
+    observing as f64 / total as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::holidays::GermanHoliday::*;
+    use crate::tests::ymd;
+
+    #[test]
+    fn observance_matrix_contains_all_regions() {
+        let matrix = observance_matrix(2019);
+        let per_region: usize = GermanRegion::all()
+            .iter()
+            .map(|region| region.holidays_in_year(2019).len())
+            .sum();
+        assert_eq!(per_region, matrix.len());
+        assert_eq!(172, matrix.len());
+        assert_eq!(
+            16,
+            matrix
+                .iter()
+                .filter(|(_, holiday)| *holiday == TagDerDeutschenEinheit)
+                .count()
+        );
+        assert!(observance_matrix(1994).is_empty());
+    }
+
+    #[test]
+    fn public_holiday_anywhere() {
+        assert!(is_public_holiday_anywhere(ymd(2019, 6, 20)));
+        assert!(!Fronleichnam.is_nationwide_public());
+        assert!(is_public_holiday_anywhere(ymd(2019, 10, 3)));
+        assert!(!is_public_holiday_anywhere(ymd(2019, 12, 24)));
+    }
+
+    #[test]
+    fn nationwide_overview_lists_observing_regions() {
+        use crate::regions::GermanRegion::*;
+        let overview = nationwide_overview(2019);
+        let einheit: Vec<_> = GermanRegion::all()
+            .iter()
+            .map(|region| (*region, TagDerDeutschenEinheit))
+            .collect();
+        assert_eq!(Some(&einheit), overview.get(&ymd(2019, 10, 3)));
+        assert_eq!(
+            Some(&vec![
+                (BadenWuerttemberg, Fronleichnam),
+                (Bayern, Fronleichnam),
+                (Hessen, Fronleichnam),
+                (NordrheinWestfalen, Fronleichnam),
+                (RheinlandPfalz, Fronleichnam),
+                (Saarland, Fronleichnam),
+            ]),
+            overview.get(&ymd(2019, 6, 20))
+        );
+        assert_eq!(None, overview.get(&ymd(2019, 6, 21)));
+        assert!(nationwide_overview(1994).is_empty());
+    }
+
+    #[test]
+    fn region_groups_with_same_holidays() {
+        use crate::regions::GermanRegion::*;
+        let groups = region_groups_by_holidays(2019);
+        assert!(groups.contains(&vec![
+            Brandenburg,
+            Bremen,
+            Hamburg,
+            MecklenburgVorpommern,
+            Niedersachsen,
+            SchleswigHolstein,
+        ]));
+        assert!(groups.contains(&vec![NordrheinWestfalen, RheinlandPfalz]));
+        assert!(groups.contains(&vec![Berlin]));
+        assert_eq!(16, groups.iter().map(Vec::len).sum::<usize>());
+        assert_eq!(
+            vec![GermanRegion::all().to_vec()],
+            region_groups_by_holidays(1994)
+        );
+    }
+
+    #[test]
+    fn population_weighted_fractions() {
+        assert_eq!(1.0, fraction_observing(TagDerDeutschenEinheit, 2019));
+        assert_eq!(0.0, fraction_observing(Heiligabend, 2019));
+        assert_eq!(0.0, fraction_observing(TagDerDeutschenEinheit, 1994));
+        let fronleichnam = fraction_observing(Fronleichnam, 2019);
+        assert!(fronleichnam > 0.6 && fronleichnam < 0.7);
+    }
+}
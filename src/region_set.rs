@@ -0,0 +1,170 @@
+use alloc::collections::BTreeMap;
+use chrono::NaiveDate;
+use core::iter::FromIterator;
+
+use crate::holidays::GermanHoliday;
+use crate::regions::GermanRegion;
+
+/// Set of regions stored as a bitset, e.g. for querying the holidays of multiple regions at once.
+///
+/// Iteration follows the declaration order of `GermanRegion`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct RegionSet {
+    // One bit per variant, there are exactly 16 regions.
+    bits: u16,
+}
+
+impl RegionSet {
+    /// Creates an empty set.
+    pub fn new() -> RegionSet {
+        RegionSet { bits: 0 }
+    }
+
+    /// Creates a set containing all regions.
+    pub fn all() -> RegionSet {
+        RegionSet { bits: u16::MAX }
+    }
+
+    /// True if the region is contained within the set.
+    pub fn contains(&self, region: GermanRegion) -> bool {
+        self.bits & bit(region) != 0
+    }
+
+    /// Adds the region to the set.
+    ///
+    /// Returns `false` if the region was already contained.
+    pub fn insert(&mut self, region: GermanRegion) -> bool {
+        let contained = self.contains(region);
+        self.bits |= bit(region);
+        !contained
+    }
+
+    /// Returns the number of regions within the set.
+    pub fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    /// True if the set contains no regions.
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    /// Iterates over the regions in declaration order.
+    pub fn iter(&self) -> RegionSetIter {
+        RegionSetIter { bits: self.bits }
+    }
+
+    /// Returns each date which is a public holiday in at least one of the regions,
+    /// together with the holiday and the regions observing it.
+    ///
+    /// If several holidays take place on the same date, e.g. Erster Mai and
+    /// Christi Himmelfahrt in 2008, the first one in calendar order is returned.
+    /// For years before 1995 the map will be empty.
+    pub fn holidays_in_year(&self, year: i32) -> BTreeMap<NaiveDate, (GermanHoliday, RegionSet)> {
+        let mut holidays: BTreeMap<NaiveDate, (GermanHoliday, RegionSet)> = BTreeMap::new();
+        for region in self.iter() {
+            for (date, holiday) in region.holiday_dates_in_year(year) {
+                holidays
+                    .entry(date)
+                    .or_insert((holiday, RegionSet::new()))
+                    .1
+                    .insert(region);
+            }
+        }
+        holidays
+    }
+}
+
+fn bit(region: GermanRegion) -> u16 {
+    1 << region as u8
+}
+
+/// Iterator over the regions of a `RegionSet`.
+#[derive(Clone, Debug)]
+pub struct RegionSetIter {
+    bits: u16,
+}
+
+impl Iterator for RegionSetIter {
+    type Item = GermanRegion;
+
+    fn next(&mut self) -> Option<GermanRegion> {
+        if self.bits == 0 {
+            return None;
+        }
+        let index = self.bits.trailing_zeros() as usize;
+        self.bits &= self.bits - 1;
+        GermanRegion::all().get(index).copied()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bits.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl IntoIterator for RegionSet {
+    type Item = GermanRegion;
+    type IntoIter = RegionSetIter;
+
+    fn into_iter(self) -> RegionSetIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<GermanRegion> for RegionSet {
+    fn from_iter<I: IntoIterator<Item = GermanRegion>>(iter: I) -> Self {
+        let mut set = RegionSet::new();
+        for region in iter {
+            set.insert(region);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::holidays::GermanHoliday::*;
+    use crate::regions::GermanRegion::*;
+    use crate::tests::ymd;
+
+    #[test]
+    fn insert_and_contains() {
+        let mut set = RegionSet::new();
+        assert!(set.is_empty());
+        assert!(set.insert(Thueringen));
+        assert!(set.insert(Bayern));
+        assert!(!set.insert(Bayern));
+        assert_eq!(2, set.len());
+        assert!(set.contains(Bayern));
+        assert!(!set.contains(Berlin));
+        assert_eq!(vec![Bayern, Thueringen], set.iter().collect::<Vec<_>>());
+        assert_eq!(
+            GermanRegion::all().to_vec(),
+            RegionSet::all().into_iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn holidays_of_two_regions() {
+        let regions: RegionSet = vec![Bayern, Berlin].into_iter().collect();
+        let holidays = regions.holidays_in_year(2019);
+        let only_bayern: RegionSet = vec![Bayern].into_iter().collect();
+        assert_eq!(
+            Some(&(Fronleichnam, only_bayern)),
+            holidays.get(&ymd(2019, 6, 20))
+        );
+        assert_eq!(
+            Some(&(TagDerDeutschenEinheit, regions)),
+            holidays.get(&ymd(2019, 10, 3))
+        );
+        assert_eq!(
+            Some(Berlin),
+            holidays
+                .get(&ymd(2019, 3, 8))
+                .and_then(|(_, regions)| regions.iter().next())
+        );
+        assert!(RegionSet::new().holidays_in_year(2019).is_empty());
+    }
+}
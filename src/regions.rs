@@ -1,54 +1,403 @@
-use chrono::{Datelike, NaiveDate};
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use chrono::{Datelike, NaiveDate, Weekday};
+use core::convert::TryFrom;
+use core::ops::{RangeFrom, RangeInclusive};
 
 /// Represents all regions and their public holidays within Germany.
 ///
 /// Holidays guaranteed to take place on sundays, e.g. easter sunday, are excluded by default.
 /// However, holidays with a fixed date can still fall on a sunday.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum GermanRegion {
     BadenWuerttemberg,
     /// * The Augsburger Friedensfest only applies to Augsburg.
-    ///   It is excluded by default, but can be calculated via `GermanHoliday::AugsburgerFriedensfest`.
+    ///   It is excluded by default, but can be calculated via `GermanHoliday::AugsburgerFriedensfest`
+    ///   or included via `RegionOptions::augsburg`.
     /// * Mariä Himmelfahrt only applies to communities with a catholic majority.
-    ///   Since this is the case in the majority of communities, it is included by default.
+    ///   Since this is the case in the majority of communities, it is included by default,
+    ///   but can be excluded via `RegionOptions::catholic_community`.
     Bayern,
     Berlin,
     Brandenburg,
     Bremen,
     Hamburg,
     Hessen,
-    MechlenburgVorpommern,
+    /// Formerly misspelled as `MechlenburgVorpommern`. The old name is only kept as the deprecated
+    /// associated constant `GermanRegion::MechlenburgVorpommern`, so it is not brought into scope
+    /// by `use GermanRegion::*` and has to be written with the type.
+    MecklenburgVorpommern,
     Niedersachsen,
     NordrheinWestfalen,
     RheinlandPfalz,
     Saarland,
     /// Fronleichnam applies only to a minority of communities and has been excluded by default.
-    /// It can be manually calculated via `GermanHoliday::Fronleichnam`.
+    /// It can be manually calculated via `GermanHoliday::Fronleichnam`
+    /// or included via `RegionOptions::sorbian_communities`.
     Sachsen,
     SachsenAnhalt,
     SchleswigHolstein,
     /// Fronleichnam applies only to a minority of communities and has been excluded by default.
-    /// It can be manually calculated via `GermanHoliday::Fronleichnam`.
+    /// It can be manually calculated via `GermanHoliday::Fronleichnam`
+    /// or included via `RegionOptions::sorbian_communities`.
     Thueringen,
 }
 
+/// Options to include holidays which only apply to some communities within a region.
+///
+/// The default matches the holidays returned by `GermanRegion::holidays_in_year`.
+///
+/// ```
+/// use holiday_de::{GermanHoliday, GermanRegion, RegionOptions};
+///
+/// let options = RegionOptions::default()
+///     .with_augsburg()
+///     .without_catholic_community();
+/// let holidays = GermanRegion::Bayern.holidays_in_year_with_options(2019, options);
+/// assert!(holidays.contains(&GermanHoliday::AugsburgerFriedensfest));
+/// assert!(!holidays.contains(&GermanHoliday::MariaeHimmelfahrt));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct RegionOptions {
+    /// Includes Fronleichnam in Sachsen and Thüringen,
+    /// which is a public holiday in the Sorbian communities of Sachsen
+    /// and in the catholic communities of Thüringen.
+    pub sorbian_communities: bool,
+    /// Includes the Augsburger Friedensfest in Bayern,
+    /// which is a public holiday in the city of Augsburg.
+    pub augsburg: bool,
+    /// Includes Mariä Himmelfahrt in Bayern, which only applies to communities
+    /// with a catholic majority. Enabled by default.
+    ///
+    /// Has no effect on Saarland, where Mariä Himmelfahrt applies to all communities.
+    pub catholic_community: bool,
+}
+
+impl Default for RegionOptions {
+    fn default() -> Self {
+        RegionOptions {
+            sorbian_communities: false,
+            augsburg: false,
+            catholic_community: true,
+        }
+    }
+}
+
+impl RegionOptions {
+    /// Enables `sorbian_communities`.
+    pub fn with_sorbian_communities(self) -> Self {
+        RegionOptions {
+            sorbian_communities: true,
+            ..self
+        }
+    }
+
+    /// Enables `augsburg`.
+    pub fn with_augsburg(self) -> Self {
+        RegionOptions {
+            augsburg: true,
+            ..self
+        }
+    }
+
+    /// Disables `catholic_community`.
+    pub fn without_catholic_community(self) -> Self {
+        RegionOptions {
+            catholic_community: false,
+            ..self
+        }
+    }
+}
+
+/// Adds or removes a public holiday for a range of years,
+/// e.g. to include newly legislated holidays not yet known to this crate.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum HolidayOverride {
+    /// Adds the holiday, unless it is already a public holiday.
+    Add {
+        holiday: GermanHoliday,
+        years: RangeInclusive<i32>,
+    },
+    /// Removes the holiday.
+    Remove {
+        holiday: GermanHoliday,
+        years: RangeInclusive<i32>,
+    },
+}
+
+use crate::calendar::HolidayCalendar;
+use crate::error::{InvalidRegionIndex, UnsupportedYear};
+use crate::holiday_set::HolidaySet;
 use crate::holidays::GermanHoliday;
 use crate::holidays::GermanHoliday::*;
 use crate::regions::GermanRegion::*;
 
 impl GermanRegion {
+    /// Misspelled name of `GermanRegion::MecklenburgVorpommern`, kept for backwards compatibility.
+    ///
+    /// Being an associated constant rather than a variant, it keeps working as
+    /// `GermanRegion::MechlenburgVorpommern`, including in patterns, but not via `use GermanRegion::*`.
+    ///
+    /// ```
+    /// # #![allow(deprecated)]
+    /// use holiday_de::GermanRegion;
+    ///
+    /// let region = GermanRegion::MechlenburgVorpommern;
+    /// assert_eq!(GermanRegion::MecklenburgVorpommern, region);
+    /// assert!(matches!(region, GermanRegion::MechlenburgVorpommern));
+    /// ```
+    #[deprecated(note = "use `GermanRegion::MecklenburgVorpommern` instead")]
+    #[allow(non_upper_case_globals)]
+    pub const MechlenburgVorpommern: GermanRegion = MecklenburgVorpommern;
+
+    /// Returns all regions in declaration order.
+    pub fn all() -> &'static [GermanRegion] {
+        &[
+            BadenWuerttemberg,
+            Bayern,
+            Berlin,
+            Brandenburg,
+            Bremen,
+            Hamburg,
+            Hessen,
+            MecklenburgVorpommern,
+            Niedersachsen,
+            NordrheinWestfalen,
+            RheinlandPfalz,
+            Saarland,
+            Sachsen,
+            SachsenAnhalt,
+            SchleswigHolstein,
+            Thueringen,
+        ]
+    }
+
+    /// Returns the index of the region in declaration order, which is the inverse of
+    /// `GermanRegion::try_from`.
+    ///
+    /// The index is stable within a crate version.
+    pub fn index(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Returns the ISO 3166-2 code of the region, e.g. `"DE-BY"` for Bayern.
+    pub fn iso_code(&self) -> &'static str {
+        match self {
+            BadenWuerttemberg => "DE-BW",
+            Bayern => "DE-BY",
+            Berlin => "DE-BE",
+            Brandenburg => "DE-BB",
+            Bremen => "DE-HB",
+            Hamburg => "DE-HH",
+            Hessen => "DE-HE",
+            MecklenburgVorpommern => "DE-MV",
+            Niedersachsen => "DE-NI",
+            NordrheinWestfalen => "DE-NW",
+            RheinlandPfalz => "DE-RP",
+            Saarland => "DE-SL",
+            Sachsen => "DE-SN",
+            SachsenAnhalt => "DE-ST",
+            SchleswigHolstein => "DE-SH",
+            Thueringen => "DE-TH",
+        }
+    }
+
+    /// Parses an ISO 3166-2 code like `"DE-BY"`, ignoring case.
+    pub fn from_iso_code(code: &str) -> Option<GermanRegion> {
+        match code.to_ascii_uppercase().as_str() {
+            "DE-BW" => Some(BadenWuerttemberg),
+            "DE-BY" => Some(Bayern),
+            "DE-BE" => Some(Berlin),
+            "DE-BB" => Some(Brandenburg),
+            "DE-HB" => Some(Bremen),
+            "DE-HH" => Some(Hamburg),
+            "DE-HE" => Some(Hessen),
+            "DE-MV" => Some(MecklenburgVorpommern),
+            "DE-NI" => Some(Niedersachsen),
+            "DE-NW" => Some(NordrheinWestfalen),
+            "DE-RP" => Some(RheinlandPfalz),
+            "DE-SL" => Some(Saarland),
+            "DE-SN" => Some(Sachsen),
+            "DE-ST" => Some(SachsenAnhalt),
+            "DE-SH" => Some(SchleswigHolstein),
+            "DE-TH" => Some(Thueringen),
+            _ => None,
+        }
+    }
+
+    /// Parses a region name leniently, ignoring case, whitespace, hyphens and underscores.
+    ///
+    /// Umlauts are folded, so `"Thüringen"` and `"Thueringen"` are both accepted.
+    /// ISO 3166-2 codes as accepted by `GermanRegion::from_iso_code` are recognized as well.
+    /// In addition the following nicknames are accepted:
+    ///
+    /// * `"BaWü"` for Baden-Württemberg
+    /// * `"MeckPomm"` for Mecklenburg-Vorpommern
+    /// * `"NRW"` for Nordrhein-Westfalen
+    pub fn parse_lenient(s: &str) -> Option<GermanRegion> {
+        if let Some(region) = GermanRegion::from_iso_code(s.trim()) {
+            return Some(region);
+        }
+        let mut normalized = String::with_capacity(s.len());
+        for c in s.chars().flat_map(char::to_lowercase) {
+            match c {
+                'ä' => normalized.push_str("ae"),
+                'ö' => normalized.push_str("oe"),
+                'ü' => normalized.push_str("ue"),
+                'ß' => normalized.push_str("ss"),
+                '-' | '_' => {}
+                c if c.is_whitespace() => {}
+                c => normalized.push(c),
+            }
+        }
+        match normalized.as_str() {
+            "badenwuerttemberg" | "bawue" => Some(BadenWuerttemberg),
+            "bayern" => Some(Bayern),
+            "berlin" => Some(Berlin),
+            "brandenburg" => Some(Brandenburg),
+            "bremen" => Some(Bremen),
+            "hamburg" => Some(Hamburg),
+            "hessen" => Some(Hessen),
+            "mecklenburgvorpommern" | "meckpomm" => Some(MecklenburgVorpommern),
+            "niedersachsen" => Some(Niedersachsen),
+            "nordrheinwestfalen" | "nrw" => Some(NordrheinWestfalen),
+            "rheinlandpfalz" => Some(RheinlandPfalz),
+            "saarland" => Some(Saarland),
+            "sachsen" => Some(Sachsen),
+            "sachsenanhalt" => Some(SachsenAnhalt),
+            "schleswigholstein" => Some(SchleswigHolstein),
+            "thueringen" => Some(Thueringen),
+            _ => None,
+        }
+    }
+
+    /// Returns the capital of the region, e.g. `"München"` for Bayern.
+    pub fn capital(&self) -> &'static str {
+        match self {
+            BadenWuerttemberg => "Stuttgart",
+            Bayern => "München",
+            Berlin => "Berlin",
+            Brandenburg => "Potsdam",
+            Bremen => "Bremen",
+            Hamburg => "Hamburg",
+            Hessen => "Wiesbaden",
+            MecklenburgVorpommern => "Schwerin",
+            Niedersachsen => "Hannover",
+            NordrheinWestfalen => "Düsseldorf",
+            RheinlandPfalz => "Mainz",
+            Saarland => "Saarbrücken",
+            Sachsen => "Dresden",
+            SachsenAnhalt => "Magdeburg",
+            SchleswigHolstein => "Kiel",
+            Thueringen => "Erfurt",
+        }
+    }
+
+    /// Returns the approximate population of the region as of 31 December 2022,
+    /// as published by the Federal Statistical Office (Destatis).
+    pub fn population(&self) -> u64 {
+        match self {
+            BadenWuerttemberg => 11_280_257,
+            Bayern => 13_369_393,
+            Berlin => 3_755_251,
+            Brandenburg => 2_573_135,
+            Bremen => 684_864,
+            Hamburg => 1_892_122,
+            Hessen => 6_391_360,
+            MecklenburgVorpommern => 1_628_378,
+            Niedersachsen => 8_140_242,
+            NordrheinWestfalen => 18_139_116,
+            RheinlandPfalz => 4_159_150,
+            Saarland => 992_666,
+            Sachsen => 4_086_152,
+            SachsenAnhalt => 2_186_643,
+            SchleswigHolstein => 2_953_270,
+            Thueringen => 2_126_846,
+        }
+    }
+
+    /// Returns all regions sharing a border with the region.
+    pub fn neighbors(&self) -> &'static [GermanRegion] {
+        match self {
+            BadenWuerttemberg => &[Bayern, Hessen, RheinlandPfalz],
+            Bayern => &[BadenWuerttemberg, Hessen, Sachsen, Thueringen],
+            Berlin => &[Brandenburg],
+            Brandenburg => &[
+                Berlin,
+                MecklenburgVorpommern,
+                Niedersachsen,
+                Sachsen,
+                SachsenAnhalt,
+            ],
+            Bremen => &[Niedersachsen],
+            Hamburg => &[Niedersachsen, SchleswigHolstein],
+            Hessen => &[
+                BadenWuerttemberg,
+                Bayern,
+                Niedersachsen,
+                NordrheinWestfalen,
+                RheinlandPfalz,
+                Thueringen,
+            ],
+            MecklenburgVorpommern => &[Brandenburg, Niedersachsen, SchleswigHolstein],
+            Niedersachsen => &[
+                Brandenburg,
+                Bremen,
+                Hamburg,
+                Hessen,
+                MecklenburgVorpommern,
+                NordrheinWestfalen,
+                SachsenAnhalt,
+                SchleswigHolstein,
+                Thueringen,
+            ],
+            NordrheinWestfalen => &[Hessen, Niedersachsen, RheinlandPfalz],
+            RheinlandPfalz => &[BadenWuerttemberg, Hessen, NordrheinWestfalen, Saarland],
+            Saarland => &[RheinlandPfalz],
+            Sachsen => &[Bayern, Brandenburg, SachsenAnhalt, Thueringen],
+            SachsenAnhalt => &[Brandenburg, Niedersachsen, Sachsen, Thueringen],
+            SchleswigHolstein => &[Hamburg, MecklenburgVorpommern, Niedersachsen],
+            Thueringen => &[Bayern, Hessen, Niedersachsen, Sachsen, SachsenAnhalt],
+        }
+    }
+
+    /// True for the states which joined in 1990 (neue Länder).
+    ///
+    /// Berlin is treated as a whole and is therefore `false`, although East Berlin belonged to the GDR.
+    pub fn is_former_east(&self) -> bool {
+        matches!(
+            self,
+            Brandenburg | MecklenburgVorpommern | Sachsen | SachsenAnhalt | Thueringen
+        )
+    }
+
     /// Returns all public holidays in the given year.
     /// Holidays guaranteed to take place on sundays, e.g. easter sunday, are excluded by default.
     ///
+    /// The holidays are ordered by their date within the given year and contain no duplicates.
+    /// Holidays whose date cannot be calculated, i.e. movable holidays after 9999, come last.
+    ///
     /// For years before 1995 this list will be empty.
     pub fn holidays_in_year(&self, year: i32) -> Vec<GermanHoliday> {
-        if year < 1995 {
-            return Vec::new();
-        }
-        let mut holidays = Vec::new();
-        holidays.extend_from_slice(BUNDESWEITE_FEIERTAGE);
-        holidays.extend_from_slice(self.region_specific_holidays(year));
-        if year == 2017 && !holidays.contains(&Reformationstag) {
+        let mut holidays = self.iter_holidays_in_year(year).collect();
+        sort_by_date(&mut holidays, year);
+        holidays
+    }
+
+    /// Iterates over all public holidays in the given year without allocating.
+    /// Unlike `GermanRegion::holidays_in_year`, the holidays are not ordered by their date.
+    /// Holidays guaranteed to take place on sundays, e.g. easter sunday, are excluded by default.
+    ///
+    /// For years before 1995 the iterator will be empty.
+    pub fn iter_holidays_in_year(&self, year: i32) -> impl Iterator<Item = GermanHoliday> {
+        // The region specific holidays are observed from 1995 at the earliest.
+        let nationwide: &[GermanHoliday] = if year < 1995 {
+            &[]
+        } else {
+            BUNDESWEITE_FEIERTAGE
+        };
+        let reformationstag = if year == 2017 {
             // BW: https://www.landesrecht-bw.de/perma?d=jlr-FeiertGBWV1P1a
             // BY: https://www.bayern.landtag.de/www/ElanTextAblage_WP17/Drucksachen/Folgedrucksachen/0000007000/0000007463.pdf
             // BE: https://gesetze.berlin.de/bsbe/document/aiz-jlr-FeiertGBErahmen%4020151025/part/x
@@ -56,84 +405,203 @@ impl GermanRegion {
             // NW: https://www.landtag.nrw.de/portal/WWW/dokumentenarchiv/Dokument?Id=XMMGVB1528%7C496%7C496
             // RP: https://web.archive.org/web/20160305005630/https://www.rlp.de/fr/aktuelles/einzelansicht/news/detail/News/zusaetzlicher-feiertag-2017/
             // SL: https://web.archive.org/web/20160306062414/http://sl.juris.de/cgi-bin/landesrecht.py?d=http%3A%2F%2Fsl.juris.de%2Fsl%2Fgesamt%2FRefT2017V_SL.htm
-            holidays.push(Reformationstag);
-        }
-        holidays
+            Some(Reformationstag)
+        } else {
+            None
+        };
+        // Regions which already observe Reformationstag must not list it twice,
+        // so every holiday is only yielded on its first occurrence.
+        let mut seen = HolidaySet::new();
+        nationwide
+            .iter()
+            .cloned()
+            .chain(self.region_specific_holidays(year))
+            .chain(reformationstag)
+            .filter(move |holiday| seen.insert(*holiday))
     }
 
-    fn region_specific_holidays(&self, year: i32) -> &'static [GermanHoliday] {
-        match self {
-            BadenWuerttemberg => &[HeiligeDreiKoenige, Fronleichnam, Allerheiligen],
-            Bayern => &[
-                HeiligeDreiKoenige,
-                Fronleichnam,
-                MariaeHimmelfahrt,
-                Allerheiligen,
-            ],
-            Berlin => {
-                if year == 2020 || year == 2025 {
-                    // 2020: https://gesetze.berlin.de/bsbe/document/aiz-jlr-FeiertGBErahmen%4020190207
-                    // 2025: https://gesetze.berlin.de/bsbe/document/jlr-FeiertGBErahmen
-                    &[Frauentag, TagDerBefreiung]
-                } else if year == 2028 {
-                    // https://gesetze.berlin.de/bsbe/document/aiz-jlr-FeiertGBErahmen%4020250509
-                    &[Frauentag, SiebzehnterJuni]
-                } else if year >= 2019 {
-                    &[Frauentag]
-                } else {
-                    &[]
-                }
-            }
-            Brandenburg => &[Reformationstag],
-            Bremen => {
-                if year >= 2017 {
-                    &[Reformationstag]
-                } else {
-                    &[]
-                }
-            }
-            Hamburg => {
-                if year >= 2017 {
-                    &[Reformationstag]
-                } else {
-                    &[]
-                }
-            }
-            Hessen => &[Fronleichnam],
-            MechlenburgVorpommern => {
-                if year >= 2023 {
-                    &[Frauentag, Reformationstag]
-                } else {
-                    &[Reformationstag]
-                }
+    /// Returns a fingerprint of the holiday rules of the region, e.g. for keying caches.
+    ///
+    /// The value is stable within a crate version and changes if the holidays
+    /// observed in any year up to 2100 change.
+    pub fn rules_version(&self) -> u64 {
+        // FNV-1a, which unlike `std::hash::DefaultHasher` is guaranteed to be stable.
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0000_0100_0000_01b3;
+        let mut hash = OFFSET_BASIS;
+        let mut write = |bytes: &[u8]| {
+            for byte in bytes {
+                hash ^= u64::from(*byte);
+                hash = hash.wrapping_mul(PRIME);
             }
-            Niedersachsen => {
-                if year >= 2017 {
-                    &[Reformationstag]
-                } else {
-                    &[]
-                }
+        };
+        write(&[*self as u8]);
+        for year in 1995..=RULES_VERSION_LAST_YEAR {
+            write(&year.to_le_bytes());
+            for holiday in self.iter_holidays_in_year(year) {
+                write(&[holiday as u8]);
             }
-            NordrheinWestfalen => &[Fronleichnam, Allerheiligen],
-            RheinlandPfalz => &[Fronleichnam, Allerheiligen],
-            Saarland => &[Fronleichnam, MariaeHimmelfahrt, Allerheiligen],
-            Sachsen => &[Reformationstag, BussUndBettag],
-            SachsenAnhalt => &[HeiligeDreiKoenige, Reformationstag],
-            SchleswigHolstein => {
-                if year >= 2017 {
-                    &[Reformationstag]
-                } else {
-                    &[]
+        }
+        hash
+    }
+
+    /// Returns all public holidays in the given year as a set with constant time membership tests.
+    ///
+    /// For years before 1995 the set will be empty.
+    pub fn holiday_set_in_year(&self, year: i32) -> HolidaySet {
+        self.iter_holidays_in_year(year).collect()
+    }
+
+    /// Returns the number of public holidays in the given year without allocating.
+    ///
+    /// Always `0` for years before 1995.
+    pub fn holiday_count_in_year(&self, year: i32) -> usize {
+        self.iter_holidays_in_year(year).count()
+    }
+
+    /// Checks if the holiday is a public holiday in the given year.
+    ///
+    /// Always `false` for years before 1995.
+    pub fn observes(&self, holiday: GermanHoliday, year: i32) -> bool {
+        self.iter_holidays_in_year(year).any(|h| h == holiday)
+    }
+
+    /// Returns the years in which the holiday is a public holiday within the region,
+    /// e.g. `Some(2019..)` for Frauentag in Berlin.
+    ///
+    /// `None` if the holiday is never observed continuously.
+    /// This includes holidays which only apply to single years,
+    /// like Reformationstag in 2017 or Tag der Befreiung in Berlin in 2020 and 2025.
+    pub fn observance_years(&self, holiday: GermanHoliday) -> Option<RangeFrom<i32>> {
+        if holiday.is_nationwide_public() {
+            return Some(1995..);
+        }
+        self.region_specific_observances()
+            .iter()
+            .find_map(|(h, observance)| match observance {
+                Observance::Since(since) if *h == holiday => Some(*since..),
+                _ => None,
+            })
+    }
+
+    /// Returns all public holidays in the given year, including the community specific
+    /// holidays enabled within the options.
+    ///
+    /// For years before 1995 this list will be empty.
+    pub fn holidays_in_year_with_options(
+        &self,
+        year: i32,
+        options: RegionOptions,
+    ) -> Vec<GermanHoliday> {
+        let mut holidays = self.holidays_in_year(year);
+        if year < 1995 {
+            return holidays;
+        }
+        if options.sorbian_communities && (*self == Sachsen || *self == Thueringen) {
+            holidays.push(Fronleichnam);
+        }
+        if options.augsburg && *self == Bayern {
+            holidays.push(AugsburgerFriedensfest);
+        }
+        if !options.catholic_community && *self == Bayern {
+            holidays.retain(|holiday| *holiday != MariaeHimmelfahrt);
+        }
+        sort_by_date(&mut holidays, year);
+        holidays
+    }
+
+    /// Returns all public holidays in the given year, with the overrides applied in order.
+    ///
+    /// For years before 1995 this list will be empty.
+    pub fn holidays_in_year_with_overrides(
+        &self,
+        year: i32,
+        overrides: &[HolidayOverride],
+    ) -> Vec<GermanHoliday> {
+        let mut holidays = self.holidays_in_year(year);
+        if year < 1995 {
+            return holidays;
+        }
+        for holiday_override in overrides {
+            match holiday_override {
+                HolidayOverride::Add { holiday, years } => {
+                    if years.contains(&year) && !holidays.contains(holiday) {
+                        holidays.push(*holiday);
+                    }
                 }
-            }
-            Thueringen => {
-                if year >= 2019 {
-                    &[Weltkindertag, Reformationstag]
-                } else {
-                    &[Reformationstag]
+                HolidayOverride::Remove { holiday, years } => {
+                    if years.contains(&year) {
+                        holidays.retain(|h| h != holiday);
+                    }
                 }
             }
         }
+        sort_by_date(&mut holidays, year);
+        holidays
+    }
+
+    /// Returns all public holidays in the given year.
+    /// Holidays guaranteed to take place on sundays, e.g. easter sunday, are excluded by default.
+    ///
+    /// Fails with `UnsupportedYear` for years before 1995.
+    pub fn try_holidays_in_year(&self, year: i32) -> Result<Vec<GermanHoliday>, UnsupportedYear> {
+        if year < 1995 {
+            return Err(UnsupportedYear::new(year));
+        }
+        Ok(self.holidays_in_year(year))
+    }
+
+    fn region_specific_holidays(&self, year: i32) -> impl Iterator<Item = GermanHoliday> {
+        self.region_specific_observances()
+            .iter()
+            .filter(move |(_, observance)| observance.applies_to(year))
+            .map(|(holiday, _)| *holiday)
+    }
+
+    /// The single source of the years in which the region specific holidays are observed.
+    fn region_specific_observances(&self) -> &'static [(GermanHoliday, Observance)] {
+        use Observance::{Only, Since};
+        match self {
+            BadenWuerttemberg => &[
+                (HeiligeDreiKoenige, Since(1995)),
+                (Fronleichnam, Since(1995)),
+                (Allerheiligen, Since(1995)),
+            ],
+            Bayern => &[
+                (HeiligeDreiKoenige, Since(1995)),
+                (Fronleichnam, Since(1995)),
+                (MariaeHimmelfahrt, Since(1995)),
+                (Allerheiligen, Since(1995)),
+            ],
+            Berlin => &[
+                (Frauentag, Since(2019)),
+                // 2020: https://gesetze.berlin.de/bsbe/document/aiz-jlr-FeiertGBErahmen%4020190207
+                // 2025: https://gesetze.berlin.de/bsbe/document/jlr-FeiertGBErahmen
+                (TagDerBefreiung, Only(&[2020, 2025])),
+                // https://gesetze.berlin.de/bsbe/document/aiz-jlr-FeiertGBErahmen%4020250509
+                (SiebzehnterJuni, Only(&[2028])),
+            ],
+            Brandenburg => &[(Reformationstag, Since(1995))],
+            Bremen => &[(Reformationstag, Since(2017))],
+            Hamburg => &[(Reformationstag, Since(2017))],
+            Hessen => &[(Fronleichnam, Since(1995))],
+            MecklenburgVorpommern => &[(Frauentag, Since(2023)), (Reformationstag, Since(1995))],
+            Niedersachsen => &[(Reformationstag, Since(2017))],
+            NordrheinWestfalen => &[(Fronleichnam, Since(1995)), (Allerheiligen, Since(1995))],
+            RheinlandPfalz => &[(Fronleichnam, Since(1995)), (Allerheiligen, Since(1995))],
+            Saarland => &[
+                (Fronleichnam, Since(1995)),
+                (MariaeHimmelfahrt, Since(1995)),
+                (Allerheiligen, Since(1995)),
+            ],
+            Sachsen => &[(Reformationstag, Since(1995)), (BussUndBettag, Since(1995))],
+            SachsenAnhalt => &[
+                (HeiligeDreiKoenige, Since(1995)),
+                (Reformationstag, Since(1995)),
+            ],
+            SchleswigHolstein => &[(Reformationstag, Since(2017))],
+            Thueringen => &[(Weltkindertag, Since(2019)), (Reformationstag, Since(1995))],
+        }
     }
 
     /// Returns all holidays and their dates in the given year.
@@ -141,102 +609,1419 @@ impl GermanRegion {
     ///
     /// For years before 1995 this list will be empty.
     pub fn holiday_dates_in_year(&self, year: i32) -> Vec<(NaiveDate, GermanHoliday)> {
-        let mut holiday_dates: Vec<(NaiveDate, GermanHoliday)> = self
-            .holidays_in_year(year)
+        // `holidays_in_year` is already ordered by date.
+        self.holidays_in_year(year)
             .into_iter()
             .flat_map(|holiday| holiday.date(year).map(|date| (date, holiday)))
-            .collect();
-        holiday_dates.sort_unstable_by_key(|(date, _)| *date);
-        holiday_dates
+            .collect()
     }
 
-    /// Checks if a given date is a public holiday in the specific region.
+    /// Returns the first public holiday and its date in the given year.
     ///
-    /// Always `false` for dates before 1995.
-    pub fn is_holiday(&self, date: NaiveDate) -> bool {
-        self.holiday_from_date(date).is_some()
+    /// Always `None` for years before 1995.
+    pub fn first_holiday_in_year(&self, year: i32) -> Option<(NaiveDate, GermanHoliday)> {
+        self.iter_holidays_in_year(year)
+            .flat_map(|holiday| holiday.date(year).map(|date| (date, holiday)))
+            .min_by_key(|(date, _)| *date)
     }
 
-    /// Returns the holiday for a specific date if the date is a holiday in the specific region.
+    /// Returns the last public holiday and its date in the given year.
     ///
-    /// Always `None` for dates before 1995.
-    pub fn holiday_from_date(&self, date: NaiveDate) -> Option<GermanHoliday> {
-        self.holidays_in_year(date.year())
+    /// Always `None` for years before 1995.
+    pub fn last_holiday_in_year(&self, year: i32) -> Option<(NaiveDate, GermanHoliday)> {
+        self.iter_holidays_in_year(year)
+            .flat_map(|holiday| holiday.date(year).map(|date| (date, holiday)))
+            .max_by_key(|(date, _)| *date)
+    }
+
+    /// Returns all public holidays and their dates in the given year,
+    /// whose date changes from year to year, see `GermanHoliday::is_movable`.
+    ///
+    /// For years before 1995 this list will be empty.
+    pub fn movable_holidays_in_year(&self, year: i32) -> Vec<(NaiveDate, GermanHoliday)> {
+        self.holiday_dates_in_year(year)
             .into_iter()
-            .find(|holiday| holiday.date(date.year()) == Some(date))
+            .filter(|(_, holiday)| holiday.is_movable())
+            .collect()
     }
-}
 
-const BUNDESWEITE_FEIERTAGE: &'static [GermanHoliday] = &[
-    Neujahr,
-    Karfreitag,
-    Ostermontag,
-    ErsterMai,
-    ChristiHimmelfahrt,
-    Pfingstmontag,
-    TagDerDeutschenEinheit,
-    ErsterWeihnachtsfeiertag,
-    ZweiterWeihnachtsfeiertag,
-];
+    /// Returns all public holidays and their dates in the given year,
+    /// which take place on the same date every year.
+    ///
+    /// For years before 1995 this list will be empty.
+    pub fn fixed_holidays_in_year(&self, year: i32) -> Vec<(NaiveDate, GermanHoliday)> {
+        self.holiday_dates_in_year(year)
+            .into_iter()
+            .filter(|(_, holiday)| !holiday.is_movable())
+            .collect()
+    }
 
-#[cfg(test)]
-mod tests {
-    use crate::regions::GermanHoliday::*;
-    use crate::regions::GermanRegion;
-    use crate::regions::GermanRegion::*;
-    use crate::DateExt;
-    use chrono::NaiveDate;
-    use proptest::prelude::*;
+    /// Returns the longest run of days without a public holiday in the given year,
+    /// as its first day, its last day and its length in days.
+    ///
+    /// Only the days strictly between two consecutive holidays count. January 1 and December 31
+    /// bound the runs before the first and after the last holiday, so for years before 1995
+    /// the whole year is returned. The earliest run wins if several are equally long.
+    ///
+    /// Years outside of the range supported by `NaiveDate` are clamped to `NaiveDate::MIN`
+    /// or `NaiveDate::MAX`, which is returned as a run of a single day.
+    pub fn longest_holiday_gap_in_year(&self, year: i32) -> (NaiveDate, NaiveDate, i64) {
+        let clamp = |date: Option<NaiveDate>| {
+            date.unwrap_or(if year < 0 {
+                NaiveDate::MIN
+            } else {
+                NaiveDate::MAX
+            })
+        };
+        let first_day = clamp(NaiveDate::from_ymd_opt(year, 1, 1));
+        let last_day = clamp(NaiveDate::from_ymd_opt(year, 12, 31));
+        let mut longest = (first_day, first_day, 0);
+        let mut consider = |start: NaiveDate, end: NaiveDate| {
+            let days = end.signed_duration_since(start).num_days() + 1;
+            if days > longest.2 {
+                longest = (start, end, days);
+            }
+        };
+        let mut run_start = Some(first_day);
+        for (date, _) in self.holiday_dates_in_year(year) {
+            if let (Some(start), Some(end)) = (run_start, date.pred_opt()) {
+                consider(start, end);
+            }
+            run_start = date.succ_opt();
+        }
+        if let Some(start) = run_start {
+            consider(start, last_day);
+        }
+        longest
+    }
 
-    #[test]
-    fn singular_example_holiday() {
-        let date = NaiveDate::from_ymd(2018, 1, 1);
-        assert!(date.is_public_holiday_in(Bayern));
-        assert_eq!(Some(Neujahr), date.public_holiday_in(Bayern));
+    /// Returns all public holidays and their dates in the given year,
+    /// flagging the holiday which takes place on `today`.
+    ///
+    /// For years before 1995 this list will be empty.
+    pub fn holidays_in_year_marking_today(
+        &self,
+        year: i32,
+        today: NaiveDate,
+    ) -> Vec<(NaiveDate, GermanHoliday, bool)> {
+        self.holiday_dates_in_year(year)
+            .into_iter()
+            .map(|(date, holiday)| (date, holiday, date == today))
+            .collect()
     }
 
-    proptest! {
-    #[test]
-    fn total_number_holidays(year in 2023i32..) {
-        let number_holidays = |region: GermanRegion| region.holidays_in_year(year).len();
-        assert_eq!(12, number_holidays(BadenWuerttemberg));
-        assert_eq!(13, number_holidays(Bayern));
-        assert_eq!(10, number_holidays(Berlin));
-        assert_eq!(10, number_holidays(Brandenburg));
-        assert_eq!(10, number_holidays(Bremen));
-        assert_eq!(10, number_holidays(Hamburg));
-        assert_eq!(10, number_holidays(Hessen));
-        assert_eq!(11, number_holidays(MechlenburgVorpommern));
-        assert_eq!(10, number_holidays(Niedersachsen));
-        assert_eq!(11, number_holidays(NordrheinWestfalen));
-        assert_eq!(11, number_holidays(RheinlandPfalz));
-        assert_eq!(12, number_holidays(Saarland));
-        assert_eq!(11, number_holidays(Sachsen));
-        assert_eq!(11, number_holidays(SachsenAnhalt));
-        assert_eq!(10, number_holidays(SchleswigHolstein));
-        assert_eq!(11, number_holidays(Thueringen));
+    /// Returns the public holidays in the given year, which are only observed
+    /// by this region and only by the other region respectively.
+    ///
+    /// Both lists are ordered by date.
+    pub fn holiday_difference(
+        &self,
+        other: GermanRegion,
+        year: i32,
+    ) -> (Vec<GermanHoliday>, Vec<GermanHoliday>) {
+        let own = self.holiday_set_in_year(year);
+        let others = other.holiday_set_in_year(year);
+        let only_own = self
+            .holidays_in_year(year)
+            .into_iter()
+            .filter(|holiday| !others.contains(*holiday))
+            .collect();
+        let only_others = other
+            .holidays_in_year(year)
+            .into_iter()
+            .filter(|holiday| !own.contains(*holiday))
+            .collect();
+        (only_own, only_others)
+    }
+
+    /// Returns all public holidays in the given year including Ostersonntag and Pfingstsonntag,
+    /// e.g. for liturgical calendars. The holidays are ordered by date.
+    ///
+    /// Apart from Brandenburg and Hessen, neither of them is a statutory public holiday.
+    /// Since both always take place on a sunday, they are excluded by
+    /// `GermanRegion::holidays_in_year` in all regions.
+    ///
+    /// For years before 1995 this list will be empty.
+    pub fn holidays_in_year_including_sundays(&self, year: i32) -> Vec<GermanHoliday> {
+        let mut holidays = self.holidays_in_year(year);
+        if year < 1995 {
+            return holidays;
+        }
+        holidays.push(Ostersonntag);
+        holidays.push(Pfingstsonntag);
+        sort_by_date(&mut holidays, year);
+        holidays
     }
+
+    /// Checks if the dates of all public holidays in the given year can be calculated.
+    ///
+    /// Easter can only be calculated for the years 1583 to 9999,
+    /// otherwise `holiday_dates_in_year` silently omits the holidays relative to easter.
+    /// Always `true` for years before 1995, since there are no holidays.
+    pub fn all_dates_computable(&self, year: i32) -> bool {
+        self.iter_holidays_in_year(year)
+            .all(|holiday| holiday.date(year).is_some())
     }
 
-    #[test]
-    fn frauentag_in_berlin_since_2019() {
-        assert!(!Berlin.holidays_in_year(2018).contains(&Frauentag));
-        assert_eq!(
-            None,
-            NaiveDate::from_ymd(2018, 3, 8).public_holiday_in(Berlin)
-        );
-        assert!(Berlin.holidays_in_year(2019).contains(&Frauentag));
-        assert_eq!(
-            Some(Frauentag),
-            NaiveDate::from_ymd(2019, 3, 8).public_holiday_in(Berlin)
-        );
+    /// Returns all holidays and their dates in the given year grouped by month,
+    /// where index 0 is January.
+    ///
+    /// For years before 1995 all months will be empty.
+    pub fn holidays_by_month(&self, year: i32) -> [Vec<(NaiveDate, GermanHoliday)>; 12] {
+        let mut months: [Vec<(NaiveDate, GermanHoliday)>; 12] = Default::default();
+        for (date, holiday) in self.holiday_dates_in_year(year) {
+            months[date.month0() as usize].push((date, holiday));
+        }
+        months
     }
 
-    proptest! {
-    #[test]
-    fn only_provide_holidays_after_1995(year in -2999i32..1995) {
-        assert!(BadenWuerttemberg.holidays_in_year(year).is_empty());
+    /// Returns all holidays and their dates in the given range of years, ordered by date.
+    ///
+    /// Years before 1995 are skipped.
+    pub fn holiday_dates_in_years(
+        &self,
+        years: RangeInclusive<i32>,
+    ) -> BTreeMap<NaiveDate, GermanHoliday> {
+        years
+            .flat_map(|year| self.holiday_dates_in_year(year))
+            .collect()
+    }
+
+    /// Returns all holidays in the given year, each starting at midnight in Europe/Berlin.
+    /// Holidays guaranteed to take place on sundays, e.g. easter sunday, are excluded by default.
+    ///
+    /// For years before 1995 this list will be empty.
+    #[cfg(feature = "timezone")]
+    pub fn holiday_datetimes_in_year(
+        &self,
+        year: i32,
+    ) -> Vec<(chrono::DateTime<chrono_tz::Tz>, GermanHoliday)> {
+        use chrono::TimeZone;
+        self.holiday_dates_in_year(year)
+            .into_iter()
+            .flat_map(|(date, holiday)| {
+                let midnight = date.and_hms_opt(0, 0, 0)?;
+                let date_time = chrono_tz::Europe::Berlin
+                    .from_local_datetime(&midnight)
+                    .single()?;
+                Some((date_time, holiday))
+            })
+            .collect()
     }
+
+    /// Checks if a given date is a public holiday in the specific region.
+    ///
+    /// Always `false` for dates before 1995.
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.holiday_from_date(date).is_some()
     }
 
+    /// Returns the holiday for a specific date if the date is a holiday in the specific region.
+    ///
+    /// Always `None` for dates before 1995.
+    pub fn holiday_from_date(&self, date: NaiveDate) -> Option<GermanHoliday> {
+        let year = date.year();
+        // Fixed dates are looked up directly, only movable holidays need to be calculated.
+        // No movable holiday listed before a fixed one can coincide with it,
+        // e.g. Erster Mai precedes Christi Himmelfahrt, both took place on 2008-05-01.
+        if let Some(holiday) = GermanHoliday::from_fixed_date(date.month(), date.day()) {
+            if self.iter_holidays_in_year(year).any(|h| h == holiday) {
+                return Some(holiday);
+            }
+        }
+        self.iter_holidays_in_year(year)
+            .filter(|holiday| holiday.fixed_date().is_none())
+            .find(|holiday| holiday.date(year) == Some(date))
+    }
+
+    /// Returns the holiday for a specific date if the date is a holiday in the specific region,
+    /// together with whether it is a public holiday in all regions,
+    /// see `GermanHoliday::is_nationwide_public`.
+    ///
+    /// Always `None` for dates before 1995.
+    pub fn holiday_kind_from_date(&self, date: NaiveDate) -> Option<(GermanHoliday, bool)> {
+        self.holiday_from_date(date)
+            .map(|holiday| (holiday, holiday.is_nationwide_public()))
+    }
+
+    /// Returns all public holidays and their dates in the given year which fall on a weekend.
+    ///
+    /// For years before 1995 this list will be empty.
+    pub fn lost_holidays_in_year(&self, year: i32) -> Vec<(NaiveDate, GermanHoliday)> {
+        self.holiday_dates_in_year(year)
+            .into_iter()
+            .filter(|(date, _)| is_weekend(*date))
+            .collect()
+    }
+
+    /// Returns the number of working days from Monday to Friday in the given year,
+    /// which are public holidays. Holidays on the same date are counted once.
+    ///
+    /// Always `0` for years before 1995.
+    pub fn working_holidays_in_year(&self, year: i32) -> usize {
+        let mut dates: Vec<NaiveDate> = self
+            .holiday_dates_in_year(year)
+            .into_iter()
+            .map(|(date, _)| date)
+            .filter(|date| !is_weekend(*date))
+            .collect();
+        dates.dedup();
+        dates.len()
+    }
+
+    /// Returns all public holidays and their dates in the given year which fall on the weekday.
+    ///
+    /// For years before 1995 this list will be empty.
+    pub fn holidays_on_weekday(
+        &self,
+        year: i32,
+        weekday: Weekday,
+    ) -> Vec<(NaiveDate, GermanHoliday)> {
+        self.holiday_dates_in_year(year)
+            .into_iter()
+            .filter(|(date, _)| date.weekday() == weekday)
+            .collect()
+    }
+
+    /// Returns all regions in which the given date is a public holiday.
+    ///
+    /// Always empty for dates before 1995.
+    pub fn regions_observing(date: NaiveDate) -> Vec<GermanRegion> {
+        GermanRegion::all()
+            .iter()
+            .cloned()
+            .filter(|region| region.is_holiday(date))
+            .collect()
+    }
+
+    /// Returns the next public holiday on or after the given date.
+    ///
+    /// For dates before 1995 this is Neujahr 1995.
+    pub fn next_holiday(&self, date: NaiveDate) -> Option<(NaiveDate, GermanHoliday)> {
+        let year = date.year().max(1995);
+        (year..=year.saturating_add(1))
+            .flat_map(|year| self.holiday_dates_in_year(year))
+            .find(|(holiday_date, _)| *holiday_date >= date)
+    }
+
+    /// Returns the next `count` public holidays from today on in chronological order,
+    /// including today.
+    ///
+    /// Today is determined by the local system clock, see `GermanRegion::upcoming_holidays_from`.
+    #[cfg(feature = "std")]
+    pub fn upcoming_holidays(&self, count: usize) -> Vec<(NaiveDate, GermanHoliday)> {
+        self.upcoming_holidays_from(chrono::Local::now().date_naive(), count)
+    }
+
+    /// Returns the next `count` public holidays on or after the given date in chronological order.
+    ///
+    /// Fewer holidays are returned if the end of the supported date range is reached.
+    pub fn upcoming_holidays_from(
+        &self,
+        date: NaiveDate,
+        count: usize,
+    ) -> Vec<(NaiveDate, GermanHoliday)> {
+        self.holiday_stream_from(date).take(count).collect()
+    }
+
+    /// Iterates over all public holidays on or after the given date in chronological order.
+    ///
+    /// The holidays are calculated lazily one year at a time.
+    /// The iterator ends with the last year supported by `NaiveDate`.
+    pub fn holiday_stream_from(
+        &self,
+        date: NaiveDate,
+    ) -> impl Iterator<Item = (NaiveDate, GermanHoliday)> {
+        let region = *self;
+        let year = date.year().max(1995);
+        (year..=NaiveDate::MAX.year())
+            .flat_map(move |year| region.holiday_dates_in_year(year))
+            .skip_while(move |(holiday_date, _)| *holiday_date < date)
+    }
+
+    /// Returns all bridge days (Brückentage) in the given year.
+    ///
+    /// A bridge day is a working day which connects a public holiday with a weekend
+    /// or another public holiday, e.g. the Friday after Christi Himmelfahrt.
+    /// Neighbouring days in the previous or next year are taken into account.
+    ///
+    /// For years before 1995 this list will be empty.
+    pub fn bridge_days_in_year(&self, year: i32) -> Vec<NaiveDate> {
+        if year < 1995 {
+            return Vec::new();
+        }
+        let first_day = match NaiveDate::from_ymd_opt(year, 1, 1) {
+            Some(date) => date,
+            None => return Vec::new(),
+        };
+        first_day
+            .iter_days()
+            .take_while(|date| date.year() == year)
+            .filter(|date| self.is_bridge_day(*date))
+            .collect()
+    }
+
+    /// Returns the number of working days, i.e. monday to friday without public holidays,
+    /// in the given month (1 to 12).
+    ///
+    /// `None` for invalid months or years outside of the range supported by `NaiveDate`.
+    pub fn working_days_in_month(&self, year: i32, month: u32) -> Option<usize> {
+        let first_day = NaiveDate::from_ymd_opt(year, month, 1)?;
+        let calendar = HolidayCalendar::new(*self, year);
+        let working_days = first_day
+            .iter_days()
+            .take_while(|date| date.month() == month)
+            .filter(|date| !is_weekend(*date) && !calendar.is_holiday(*date))
+            .count();
+        Some(working_days)
+    }
+
+    /// Counts all working days, i.e. monday to friday without public holidays,
+    /// from `start` to `end` (inclusive).
+    pub fn count_business_days(&self, start: NaiveDate, end: NaiveDate) -> usize {
+        start
+            .iter_days()
+            .take_while(|date| *date <= end)
+            .filter(|date| self.is_working_day(*date))
+            .count()
+    }
+
+    /// Iterates over all working days, i.e. monday to friday without public holidays,
+    /// from `start` to `end` (inclusive).
+    ///
+    /// The holidays are only calculated once per year.
+    pub fn business_days(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> impl Iterator<Item = NaiveDate> {
+        let region = *self;
+        let mut calendar: Option<HolidayCalendar> = None;
+        start
+            .iter_days()
+            .take_while(move |date| *date <= end)
+            .filter(move |date| {
+                let calendar = match &mut calendar {
+                    Some(calendar) if calendar.year() == date.year() => calendar,
+                    calendar => calendar.insert(HolidayCalendar::new(region, date.year())),
+                };
+                !is_weekend(*date) && !calendar.is_holiday(*date)
+            })
+    }
+
+    /// Returns all long weekends in the given year as first and last day.
+    ///
+    /// A long weekend is a span of at least three consecutive non-working days
+    /// in which a public holiday touches a weekend, e.g. Karfreitag to Ostermontag.
+    /// Spans may extend into the previous or next year.
+    ///
+    /// For years before 1995 this list will be empty.
+    pub fn long_weekends_in_year(&self, year: i32) -> Vec<(NaiveDate, NaiveDate)> {
+        let mut long_weekends: Vec<(NaiveDate, NaiveDate)> = Vec::new();
+        for (date, _) in self.holiday_dates_in_year(year) {
+            let mut start = date;
+            while let Some(previous) = start.pred_opt().filter(|d| !self.is_working_day(*d)) {
+                start = previous;
+            }
+            let mut end = date;
+            while let Some(next) = end.succ_opt().filter(|d| !self.is_working_day(*d)) {
+                end = next;
+            }
+            let touches_weekend = start.iter_days().take_while(|d| *d <= end).any(is_weekend);
+            let is_long = end.signed_duration_since(start).num_days() >= 2;
+            if touches_weekend && is_long && long_weekends.last() != Some(&(start, end)) {
+                long_weekends.push((start, end));
+            }
+        }
+        long_weekends
+    }
+
+    fn is_bridge_day(&self, date: NaiveDate) -> bool {
+        let (previous, next) = match (date.pred_opt(), date.succ_opt()) {
+            (Some(previous), Some(next)) => (previous, next),
+            _ => return false,
+        };
+        self.is_working_day(date)
+            && !self.is_working_day(previous)
+            && !self.is_working_day(next)
+            && (self.is_holiday(previous) || self.is_holiday(next))
+    }
+
+    pub(crate) fn is_working_day(&self, date: NaiveDate) -> bool {
+        !is_weekend(date) && !self.is_holiday(date)
+    }
+}
+
+/// Orders the holidays by their date within the year and removes duplicates.
+///
+/// The date of every holiday is only calculated once.
+/// Holidays on the same date, e.g. Erster Mai and Christi Himmelfahrt in 2008,
+/// are ordered by `GermanHoliday`'s `Ord`. Holidays whose date cannot be calculated come last.
+fn sort_by_date(holidays: &mut Vec<GermanHoliday>, year: i32) {
+    // Years outside of the range of `NaiveDate` have no dates at all.
+    let has_dates = NaiveDate::from_ymd_opt(year, 1, 1).is_some();
+    let mut dated: Vec<(Option<NaiveDate>, GermanHoliday)> = holidays
+        .iter()
+        .map(|holiday| {
+            let date = if has_dates { holiday.date(year) } else { None };
+            (date, *holiday)
+        })
+        .collect();
+    dated.sort_unstable_by_key(|(date, holiday)| (date.is_none(), *date, *holiday));
+    dated.dedup_by_key(|(_, holiday)| *holiday);
+    holidays.clear();
+    holidays.extend(dated.into_iter().map(|(_, holiday)| holiday));
+}
+
+/// Years in which a region specific holiday is a public holiday.
+#[derive(Clone, Copy, Debug)]
+enum Observance {
+    /// Every year starting with the given one.
+    Since(i32),
+    /// Only the given years.
+    Only(&'static [i32]),
+}
+
+impl Observance {
+    fn applies_to(self, year: i32) -> bool {
+        match self {
+            Observance::Since(since) => year >= since,
+            Observance::Only(years) => years.contains(&year),
+        }
+    }
+}
+
+/// Last year considered by `GermanRegion::rules_version`.
+const RULES_VERSION_LAST_YEAR: i32 = 2100;
+
+fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+/// Converts the index in declaration order, see `GermanRegion::index`.
+impl TryFrom<u8> for GermanRegion {
+    type Error = InvalidRegionIndex;
+
+    fn try_from(index: u8) -> Result<Self, Self::Error> {
+        GermanRegion::all()
+            .get(usize::from(index))
+            .copied()
+            .ok_or_else(|| InvalidRegionIndex::new(index))
+    }
+}
+
+/// Serializes regions by their ISO 3166-2 code, e.g. `"DE-TH"`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for GermanRegion {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.iso_code())
+    }
+}
+
+/// Deserializes regions from their ISO 3166-2 code, ignoring case.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for GermanRegion {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = alloc::string::String::deserialize(deserializer)?;
+        GermanRegion::from_iso_code(&code).ok_or_else(|| {
+            serde::de::Error::invalid_value(
+                serde::de::Unexpected::Str(&code),
+                &"an ISO 3166-2 code of a German region",
+            )
+        })
+    }
+}
+
+pub(crate) const BUNDESWEITE_FEIERTAGE: &[GermanHoliday] = &[
+    Neujahr,
+    Karfreitag,
+    Ostermontag,
+    ErsterMai,
+    ChristiHimmelfahrt,
+    Pfingstmontag,
+    TagDerDeutschenEinheit,
+    ErsterWeihnachtsfeiertag,
+    ZweiterWeihnachtsfeiertag,
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::regions::GermanHoliday;
+    use crate::regions::GermanHoliday::*;
+    use crate::regions::GermanRegion;
+    use crate::regions::GermanRegion::*;
+    use crate::regions::{HolidayOverride, RegionOptions};
+    use crate::tests::ymd;
+    use crate::DateExt;
+    use chrono::{Datelike, NaiveDate, Weekday};
+    use proptest::prelude::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn singular_example_holiday() {
+        let date = ymd(2018, 1, 1);
+        assert!(date.is_public_holiday_in(Bayern));
+        assert_eq!(Some(Neujahr), date.public_holiday_in(Bayern));
+    }
+
+    proptest! {
+    #[test]
+    fn total_number_holidays(year in 2023i32..) {
+        let number_holidays = |region: GermanRegion| region.holidays_in_year(year).len();
+        assert_eq!(12, number_holidays(BadenWuerttemberg));
+        assert_eq!(13, number_holidays(Bayern));
+        assert_eq!(10, number_holidays(Berlin));
+        assert_eq!(10, number_holidays(Brandenburg));
+        assert_eq!(10, number_holidays(Bremen));
+        assert_eq!(10, number_holidays(Hamburg));
+        assert_eq!(10, number_holidays(Hessen));
+        assert_eq!(11, number_holidays(MecklenburgVorpommern));
+        assert_eq!(10, number_holidays(Niedersachsen));
+        assert_eq!(11, number_holidays(NordrheinWestfalen));
+        assert_eq!(11, number_holidays(RheinlandPfalz));
+        assert_eq!(12, number_holidays(Saarland));
+        assert_eq!(11, number_holidays(Sachsen));
+        assert_eq!(11, number_holidays(SachsenAnhalt));
+        assert_eq!(10, number_holidays(SchleswigHolstein));
+        assert_eq!(11, number_holidays(Thueringen));
+    }
+    }
+
+    proptest! {
+    #[test]
+    fn holiday_count_matches_holidays_in_year(year in 1990i32..) {
+        for region in GermanRegion::all() {
+            assert_eq!(region.holidays_in_year(year).len(), region.holiday_count_in_year(year));
+        }
+    }
+    }
+
+    #[test]
+    fn frauentag_in_berlin_since_2019() {
+        assert!(!Berlin.holidays_in_year(2018).contains(&Frauentag));
+        assert_eq!(None, ymd(2018, 3, 8).public_holiday_in(Berlin));
+        assert!(Berlin.holidays_in_year(2019).contains(&Frauentag));
+        assert_eq!(Some(Frauentag), ymd(2019, 3, 8).public_holiday_in(Berlin));
+    }
+
+    proptest! {
+    #[test]
+    fn only_provide_holidays_after_1995(year in -2999i32..1995) {
+        assert!(BadenWuerttemberg.holidays_in_year(year).is_empty());
+    }
+    }
+
+    #[test]
+    fn friday_after_christi_himmelfahrt_is_bridge_day() {
+        let bridge_days = Berlin.bridge_days_in_year(2019);
+        assert!(bridge_days.contains(&ymd(2019, 5, 31)));
+    }
+
+    #[test]
+    fn bridge_days_in_bayern() {
+        // 2019: Allerheiligen on a Friday does not create a bridge day
+        let bridge_days = Bayern.bridge_days_in_year(2019);
+        assert_eq!(
+            vec![
+                ymd(2019, 5, 31),
+                ymd(2019, 6, 21),
+                ymd(2019, 8, 16),
+                ymd(2019, 10, 4),
+                ymd(2019, 12, 27),
+            ],
+            bridge_days
+        );
+    }
+
+    #[test]
+    fn bridge_day_next_to_holiday_in_next_year() {
+        // 2018-12-31 is a Monday followed by Neujahr on Tuesday
+        assert!(Hamburg
+            .bridge_days_in_year(2018)
+            .contains(&ymd(2018, 12, 31)));
+    }
+
+    #[test]
+    fn iso_codes() {
+        assert_eq!("DE-BY", Bayern.iso_code());
+        assert_eq!("DE-NW", NordrheinWestfalen.iso_code());
+        assert_eq!(Some(Bayern), GermanRegion::from_iso_code("DE-BY"));
+        assert_eq!(Some(Thueringen), GermanRegion::from_iso_code("de-th"));
+        assert_eq!(None, GermanRegion::from_iso_code("DE-XX"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_uses_iso_codes() {
+        assert_eq!("\"DE-TH\"", serde_json::to_string(&Thueringen).unwrap());
+        let region: GermanRegion = serde_json::from_str("\"DE-MV\"").unwrap();
+        assert_eq!(MecklenburgVorpommern, region);
+        assert!(serde_json::from_str::<GermanRegion>("\"DE-XX\"").is_err());
+    }
+
+    #[test]
+    fn all_regions_are_distinct() {
+        let regions: HashSet<GermanRegion> = GermanRegion::all().iter().cloned().collect();
+        assert_eq!(16, regions.len());
+    }
+
+    #[test]
+    fn regions_observing_holidays() {
+        assert_eq!(
+            GermanRegion::all(),
+            &GermanRegion::regions_observing(ymd(2019, 10, 3))[..]
+        );
+        assert_eq!(
+            vec![
+                BadenWuerttemberg,
+                Bayern,
+                Hessen,
+                NordrheinWestfalen,
+                RheinlandPfalz,
+                Saarland
+            ],
+            GermanRegion::regions_observing(ymd(2019, 6, 20))
+        );
+        assert_eq!(
+            GermanRegion::all(),
+            &GermanRegion::regions_observing(ymd(2017, 10, 31))[..]
+        );
+        assert!(GermanRegion::regions_observing(ymd(2019, 12, 24)).is_empty());
+    }
+
+    #[test]
+    fn unsupported_years_are_errors() {
+        assert_eq!(
+            Ok(Bayern.holidays_in_year(1995)),
+            Bayern.try_holidays_in_year(1995)
+        );
+        let error = Bayern.try_holidays_in_year(1994).unwrap_err();
+        assert_eq!(1994, error.year());
+    }
+
+    #[test]
+    fn lost_holidays_on_weekends() {
+        // 2022: Neujahr on a Saturday, Erster Mai and Erster Weihnachtsfeiertag on Sundays
+        assert_eq!(
+            vec![
+                (ymd(2022, 1, 1), Neujahr),
+                (ymd(2022, 5, 1), ErsterMai),
+                (ymd(2022, 12, 25), ErsterWeihnachtsfeiertag),
+            ],
+            Hessen.lost_holidays_in_year(2022)
+        );
+    }
+
+    #[test]
+    fn holiday_dates_across_years() {
+        let holidays = Hessen.holiday_dates_in_years(2019..=2020);
+        assert_eq!(20, holidays.len());
+        assert_eq!(
+            vec![
+                (&ymd(2019, 12, 25), &ErsterWeihnachtsfeiertag),
+                (&ymd(2019, 12, 26), &ZweiterWeihnachtsfeiertag),
+                (&ymd(2020, 1, 1), &Neujahr),
+            ],
+            holidays
+                .range(ymd(2019, 12, 1)..ymd(2020, 2, 1))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(10, Hessen.holiday_dates_in_years(1990..=1995).len());
+    }
+
+    #[test]
+    fn fronleichnam_in_sorbian_communities() {
+        let options = RegionOptions {
+            sorbian_communities: true,
+            ..RegionOptions::default()
+        };
+        for region in &[Sachsen, Thueringen] {
+            let holidays = region.holidays_in_year_with_options(2019, options);
+            assert!(holidays.contains(&Fronleichnam));
+            assert_eq!(region.holidays_in_year(2019).len() + 1, holidays.len());
+        }
+        assert_eq!(
+            Hessen.holidays_in_year(2019),
+            Hessen.holidays_in_year_with_options(2019, options)
+        );
+        assert_eq!(
+            Sachsen.holidays_in_year(2019),
+            Sachsen.holidays_in_year_with_options(2019, RegionOptions::default())
+        );
+    }
+
+    #[test]
+    fn augsburger_friedensfest_in_augsburg() {
+        let options = RegionOptions {
+            augsburg: true,
+            ..RegionOptions::default()
+        };
+        let holidays = Bayern.holidays_in_year_with_options(2019, options);
+        assert_eq!(14, holidays.len());
+        assert!(holidays.contains(&AugsburgerFriedensfest));
+        for region in GermanRegion::all()
+            .iter()
+            .filter(|region| **region != Bayern)
+        {
+            assert!(!region
+                .holidays_in_year_with_options(2019, options)
+                .contains(&AugsburgerFriedensfest));
+        }
+    }
+
+    #[test]
+    fn mariae_himmelfahrt_in_protestant_communities() {
+        let options = RegionOptions {
+            catholic_community: false,
+            ..RegionOptions::default()
+        };
+        assert!(Bayern
+            .holidays_in_year_with_options(2019, RegionOptions::default())
+            .contains(&MariaeHimmelfahrt));
+        assert!(!Bayern
+            .holidays_in_year_with_options(2019, options)
+            .contains(&MariaeHimmelfahrt));
+        assert!(Saarland
+            .holidays_in_year_with_options(2019, options)
+            .contains(&MariaeHimmelfahrt));
+    }
+
+    proptest! {
+    #[test]
+    fn iterator_matches_holidays_in_year(year in 1990i32..2050) {
+        for region in GermanRegion::all() {
+            let mut holidays = region.iter_holidays_in_year(year).collect::<Vec<_>>();
+            holidays.sort_by_cached_key(|holiday| (holiday.date(year), *holiday));
+            assert_eq!(region.holidays_in_year(year), holidays);
+        }
+    }
+    }
+
+    #[test]
+    fn reformationstag_2017_only_once() {
+        let count = |region: GermanRegion| {
+            region
+                .holidays_in_year(2017)
+                .into_iter()
+                .filter(|holiday| *holiday == Reformationstag)
+                .count()
+        };
+        assert_eq!(1, count(Brandenburg));
+        assert_eq!(1, count(Sachsen));
+        for region in GermanRegion::all() {
+            assert_eq!(1, count(*region));
+            assert_eq!(
+                1,
+                region
+                    .iter_holidays_in_year(2017)
+                    .filter(|holiday| *holiday == Reformationstag)
+                    .count()
+            );
+        }
+    }
+
+    #[test]
+    fn working_days_in_month() {
+        // May 2019: 23 weekdays, Erster Mai on Wednesday, Christi Himmelfahrt on Thursday
+        assert_eq!(Some(21), Berlin.working_days_in_month(2019, 5));
+        // February 29th 2024 is a Thursday
+        assert_eq!(Some(21), Berlin.working_days_in_month(2024, 2));
+        assert_eq!(Some(20), Berlin.working_days_in_month(2023, 2));
+        assert_eq!(None, Berlin.working_days_in_month(2019, 0));
+        assert_eq!(None, Berlin.working_days_in_month(2019, 13));
+        assert_eq!(None, Berlin.working_days_in_month(i32::MAX, 1));
+    }
+
+    #[test]
+    fn long_weekends() {
+        // Frauentag on Friday, Easter from Karfreitag to Ostermontag and Pfingstmontag
+        let long_weekends = Berlin.long_weekends_in_year(2019);
+        assert_eq!(
+            vec![
+                (ymd(2019, 3, 8), ymd(2019, 3, 10)),
+                (ymd(2019, 4, 19), ymd(2019, 4, 22)),
+                (ymd(2019, 6, 8), ymd(2019, 6, 10)),
+            ],
+            long_weekends
+        );
+    }
+
+    #[test]
+    fn holidays_grouped_by_month() {
+        for region in GermanRegion::all() {
+            let months = region.holidays_by_month(2019);
+            assert_eq!(
+                Some(&Neujahr),
+                months[0].first().map(|(_, holiday)| holiday)
+            );
+            let december: Vec<_> = months[11].iter().map(|(_, holiday)| *holiday).collect();
+            assert!(december.contains(&ErsterWeihnachtsfeiertag));
+            assert!(december.contains(&ZweiterWeihnachtsfeiertag));
+        }
+        assert!(Berlin.holidays_by_month(1994).iter().all(Vec::is_empty));
+    }
+
+    #[test]
+    fn former_east_regions() {
+        let east: Vec<GermanRegion> = GermanRegion::all()
+            .iter()
+            .cloned()
+            .filter(GermanRegion::is_former_east)
+            .collect();
+        assert_eq!(
+            vec![
+                Brandenburg,
+                MecklenburgVorpommern,
+                Sachsen,
+                SachsenAnhalt,
+                Thueringen
+            ],
+            east
+        );
+    }
+
+    #[test]
+    fn observes_year_gated_holidays() {
+        assert!(!Berlin.observes(Frauentag, 2018));
+        assert!(Berlin.observes(Frauentag, 2019));
+        assert!(!Hamburg.observes(Reformationstag, 2016));
+        assert!(Hamburg.observes(Reformationstag, 2017));
+        assert!(Bayern.observes(Reformationstag, 2017));
+        assert!(!Bayern.observes(Reformationstag, 2018));
+        assert!(!Bayern.observes(Neujahr, 1994));
+    }
+
+    #[test]
+    fn business_days_across_years() {
+        let start = ymd(2018, 12, 1);
+        let end = ymd(2019, 1, 31);
+        let business_days: Vec<NaiveDate> = Hessen.business_days(start, end).collect();
+        assert_eq!(Hessen.count_business_days(start, end), business_days.len());
+        assert_eq!(19 + 22, business_days.len());
+        assert!(!business_days.contains(&ymd(2019, 1, 1)));
+        assert!(business_days.windows(2).all(|days| days[0] < days[1]));
+        assert_eq!(0, Hessen.business_days(end, start).count());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn misspelled_mecklenburg_vorpommern() {
+        assert_eq!(MecklenburgVorpommern, GermanRegion::MechlenburgVorpommern);
+        match MecklenburgVorpommern {
+            GermanRegion::MechlenburgVorpommern => {}
+            _ => panic!("the misspelled name must still match MecklenburgVorpommern"),
+        }
+        assert_eq!("DE-MV", MecklenburgVorpommern.iso_code());
+        assert_eq!(11, MecklenburgVorpommern.holidays_in_year(2023).len());
+    }
+
+    #[test]
+    fn dates_computable() {
+        assert!(Bayern.all_dates_computable(2019));
+        assert!(Bayern.all_dates_computable(9999));
+        assert!(!Bayern.all_dates_computable(10000));
+    }
+
+    #[test]
+    fn holidays_on_mondays() {
+        assert_eq!(
+            vec![
+                (ymd(2019, 4, 22), Ostermontag),
+                (ymd(2019, 6, 10), Pfingstmontag),
+            ],
+            Berlin.holidays_on_weekday(2019, Weekday::Mon)
+        );
+    }
+
+    #[test]
+    fn region_options_setters() {
+        assert_eq!(
+            RegionOptions {
+                sorbian_communities: true,
+                augsburg: true,
+                catholic_community: false,
+            },
+            RegionOptions::default()
+                .with_sorbian_communities()
+                .with_augsburg()
+                .without_catholic_community()
+        );
+        assert_eq!(
+            Sachsen.holidays_in_year(2019),
+            Sachsen.holidays_in_year_with_options(2019, RegionOptions::default())
+        );
+    }
+
+    #[test]
+    fn capitals() {
+        assert_eq!("Berlin", Berlin.capital());
+        assert_eq!("München", Bayern.capital());
+        assert_eq!("Düsseldorf", NordrheinWestfalen.capital());
+    }
+
+    #[test]
+    fn overrides_add_and_remove_holidays() {
+        let overrides = [
+            HolidayOverride::Add {
+                holiday: Weltkindertag,
+                years: 2030..=2031,
+            },
+            HolidayOverride::Remove {
+                holiday: Frauentag,
+                years: 2031..=2031,
+            },
+        ];
+        let holidays = |year| Berlin.holidays_in_year_with_overrides(year, &overrides);
+        assert_eq!(Berlin.holidays_in_year(2029), holidays(2029));
+        assert!(holidays(2030).contains(&Weltkindertag));
+        assert!(holidays(2030).contains(&Frauentag));
+        assert!(holidays(2031).contains(&Weltkindertag));
+        assert!(!holidays(2031).contains(&Frauentag));
+        assert_eq!(Berlin.holidays_in_year(2032), holidays(2032));
+    }
+
+    #[test]
+    fn overrides_do_not_duplicate_holidays() {
+        let overrides = [HolidayOverride::Add {
+            holiday: Neujahr,
+            years: 1995..=2100,
+        }];
+        assert_eq!(
+            Bayern.holidays_in_year(2019),
+            Bayern.holidays_in_year_with_overrides(2019, &overrides)
+        );
+    }
+
+    #[test]
+    fn next_holidays() {
+        assert_eq!(
+            Some((ymd(2019, 12, 25), ErsterWeihnachtsfeiertag)),
+            Berlin.next_holiday(ymd(2019, 12, 24))
+        );
+        assert_eq!(
+            Some((ymd(2020, 1, 1), Neujahr)),
+            Berlin.next_holiday(ymd(2019, 12, 27))
+        );
+        assert_eq!(
+            Some((ymd(1995, 1, 1), Neujahr)),
+            Berlin.next_holiday(ymd(1990, 6, 1))
+        );
+    }
+
+    #[cfg(feature = "timezone")]
+    #[test]
+    fn holiday_datetimes_in_berlin() {
+        use chrono::{TimeZone, Utc};
+        let date_times = Bayern.holiday_datetimes_in_year(2019);
+        assert_eq!(13, date_times.len());
+        let (neujahr, holiday) = date_times[0];
+        assert_eq!(Neujahr, holiday);
+        assert_eq!(
+            Utc.with_ymd_and_hms(2018, 12, 31, 23, 0, 0).unwrap(),
+            neujahr.with_timezone(&Utc)
+        );
+        let (mariae_himmelfahrt, _) = date_times
+            .iter()
+            .find(|(_, holiday)| *holiday == MariaeHimmelfahrt)
+            .unwrap();
+        assert_eq!(
+            Utc.with_ymd_and_hms(2019, 8, 14, 22, 0, 0).unwrap(),
+            mariae_himmelfahrt.with_timezone(&Utc)
+        );
+    }
+
+    #[test]
+    fn holiday_count_with_year_gated_holidays() {
+        assert_eq!(9, Berlin.holiday_count_in_year(2018));
+        assert_eq!(10, Berlin.holiday_count_in_year(2019));
+        assert_eq!(11, Berlin.holiday_count_in_year(2020));
+        assert_eq!(14, Bayern.holiday_count_in_year(2017));
+        assert_eq!(0, Bayern.holiday_count_in_year(1994));
+    }
+
+    #[test]
+    fn observance_years_of_holidays() {
+        assert_eq!(Some(2019..), Berlin.observance_years(Frauentag));
+        assert_eq!(Some(2017..), Hamburg.observance_years(Reformationstag));
+        assert_eq!(Some(1995..), Brandenburg.observance_years(Reformationstag));
+        assert_eq!(Some(1995..), Bayern.observance_years(Neujahr));
+        assert_eq!(None, Bayern.observance_years(Reformationstag));
+        assert_eq!(None, Berlin.observance_years(TagDerBefreiung));
+        assert_eq!(None, Bayern.observance_years(Heiligabend));
+    }
+
+    #[test]
+    fn observance_years_match_holidays_in_year() {
+        let one_off_years = [2017, 2020, 2025, 2028];
+        for year in (1995..2050).filter(|year| !one_off_years.contains(year)) {
+            for region in GermanRegion::all() {
+                for holiday in GermanHoliday::all() {
+                    let observed = region
+                        .observance_years(*holiday)
+                        .is_some_and(|years| years.contains(&year));
+                    assert_eq!(region.observes(*holiday, year), observed);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn neighbors_are_symmetric() {
+        assert_eq!(&[Brandenburg], Berlin.neighbors());
+        assert_eq!(
+            &[BadenWuerttemberg, Hessen, Sachsen, Thueringen],
+            Bayern.neighbors()
+        );
+        for region in GermanRegion::all() {
+            assert!(!region.neighbors().contains(region));
+            for neighbor in region.neighbors() {
+                assert!(neighbor.neighbors().contains(region));
+            }
+        }
+    }
+
+    #[test]
+    fn parse_lenient_accepts_messy_input() {
+        assert_eq!(
+            Some(MecklenburgVorpommern),
+            GermanRegion::parse_lenient("mecklenburg vorpommern")
+        );
+        assert_eq!(
+            Some(MecklenburgVorpommern),
+            GermanRegion::parse_lenient("Mecklenburg-Vorpommern")
+        );
+        assert_eq!(
+            Some(MecklenburgVorpommern),
+            GermanRegion::parse_lenient("MeckPomm")
+        );
+        assert_eq!(Some(Thueringen), GermanRegion::parse_lenient(" THÜRINGEN "));
+        assert_eq!(
+            Some(BadenWuerttemberg),
+            GermanRegion::parse_lenient("Baden-Württemberg")
+        );
+        assert_eq!(Some(BadenWuerttemberg), GermanRegion::parse_lenient("BaWü"));
+        assert_eq!(Some(NordrheinWestfalen), GermanRegion::parse_lenient("nrw"));
+        assert_eq!(Some(Bayern), GermanRegion::parse_lenient("de-by"));
+        assert_eq!(
+            Some(SchleswigHolstein),
+            GermanRegion::parse_lenient("schleswig_holstein")
+        );
+        assert_eq!(None, GermanRegion::parse_lenient("Sachsen Anhalt Nord"));
+        assert_eq!(None, GermanRegion::parse_lenient(""));
+    }
+
+    #[test]
+    fn holiday_from_date_matches_calculation_of_all_dates() {
+        for region in GermanRegion::all() {
+            for year in &[1994, 2008, 2017, 2019, 2025, 2028] {
+                let mut date = ymd(*year, 1, 1);
+                while date.year() == *year {
+                    let expected = region
+                        .holidays_in_year(*year)
+                        .into_iter()
+                        .find(|holiday| holiday.date(*year) == Some(date));
+                    assert_eq!(expected, region.holiday_from_date(date));
+                    date = date.succ_opt().unwrap();
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rules_version_is_deterministic() {
+        for region in GermanRegion::all() {
+            assert_eq!(region.rules_version(), region.rules_version());
+        }
+        // Hamburg and Schleswig-Holstein share the same holidays in all years.
+        assert_ne!(Hamburg.rules_version(), SchleswigHolstein.rules_version());
+        assert_ne!(Berlin.rules_version(), Brandenburg.rules_version());
+    }
+
+    #[test]
+    fn first_and_last_holiday_in_year() {
+        for region in GermanRegion::all() {
+            for year in 1995..2100 {
+                assert_eq!(
+                    Some((ymd(year, 1, 1), Neujahr)),
+                    region.first_holiday_in_year(year)
+                );
+                assert_eq!(
+                    Some((ymd(year, 12, 26), ZweiterWeihnachtsfeiertag)),
+                    region.last_holiday_in_year(year)
+                );
+            }
+            assert_eq!(None, region.first_holiday_in_year(1994));
+            assert_eq!(None, region.last_holiday_in_year(1994));
+        }
+    }
+
+    #[test]
+    fn regions_use_the_full_holiday_enum() {
+        let holidays: Vec<crate::holidays::GermanHoliday> = Bayern.holidays_in_year(2019);
+        assert!(holidays
+            .iter()
+            .all(|holiday| GermanHoliday::all().contains(holiday)));
+        for non_public in &[Heiligabend, Silvester, Aschermittwoch, Muttertag] {
+            assert!(GermanHoliday::all().contains(non_public));
+            assert!(GermanRegion::all()
+                .iter()
+                .all(|region| !region.holidays_in_year(2019).contains(non_public)));
+        }
+    }
+
+    #[test]
+    fn upcoming_holidays_cross_year_boundaries() {
+        assert_eq!(
+            vec![
+                (ymd(2019, 12, 25), ErsterWeihnachtsfeiertag),
+                (ymd(2019, 12, 26), ZweiterWeihnachtsfeiertag),
+                (ymd(2020, 1, 1), Neujahr),
+                (ymd(2020, 3, 8), Frauentag),
+            ],
+            Berlin.upcoming_holidays_from(ymd(2019, 12, 25), 4)
+        );
+        assert!(Berlin
+            .upcoming_holidays_from(ymd(2019, 12, 25), 0)
+            .is_empty());
+        assert_eq!(
+            Some((ymd(1995, 1, 1), Neujahr)),
+            Bayern.upcoming_holidays_from(ymd(1990, 6, 1), 1).pop()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn upcoming_holidays_from_now() {
+        let upcoming = Bayern.upcoming_holidays(3);
+        assert_eq!(3, upcoming.len());
+        assert!(upcoming.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn holidays_in_year_are_ordered_without_duplicates() {
+        for region in GermanRegion::all() {
+            let holidays = region.holidays_in_year(2017);
+            let unique: HashSet<_> = holidays.iter().collect();
+            assert_eq!(unique.len(), holidays.len());
+            assert!(holidays
+                .windows(2)
+                .all(|w| w[0].date(2017) <= w[1].date(2017)));
+        }
+        assert_eq!(
+            vec![
+                Neujahr,
+                HeiligeDreiKoenige,
+                Karfreitag,
+                Ostermontag,
+                ErsterMai,
+                ChristiHimmelfahrt,
+                Pfingstmontag,
+                Fronleichnam,
+                TagDerDeutschenEinheit,
+                Reformationstag,
+                Allerheiligen,
+                ErsterWeihnachtsfeiertag,
+                ZweiterWeihnachtsfeiertag,
+            ],
+            BadenWuerttemberg.holidays_in_year(2017)
+        );
+        assert_eq!(
+            &[ErsterMai, ChristiHimmelfahrt],
+            &Bayern.holidays_in_year(2008)[4..6]
+        );
+        // Easter can only be calculated up to 9999.
+        assert_eq!(
+            vec![
+                Neujahr,
+                HeiligeDreiKoenige,
+                ErsterMai,
+                MariaeHimmelfahrt,
+                TagDerDeutschenEinheit,
+                Allerheiligen,
+                ErsterWeihnachtsfeiertag,
+                ZweiterWeihnachtsfeiertag,
+                Karfreitag,
+                Ostermontag,
+                ChristiHimmelfahrt,
+                Pfingstmontag,
+                Fronleichnam,
+            ],
+            Bayern.holidays_in_year(10000)
+        );
+    }
+
+    #[test]
+    fn holiday_kinds() {
+        assert_eq!(
+            Some((Fronleichnam, false)),
+            Bayern.holiday_kind_from_date(ymd(2019, 6, 20))
+        );
+        assert_eq!(
+            Some((TagDerDeutschenEinheit, true)),
+            Bayern.holiday_kind_from_date(ymd(2019, 10, 3))
+        );
+        assert_eq!(None, Berlin.holiday_kind_from_date(ymd(2019, 6, 20)));
+    }
+
+    #[test]
+    fn index_round_trip() {
+        use core::convert::TryFrom;
+        for (index, region) in GermanRegion::all().iter().enumerate() {
+            assert_eq!(index, usize::from(region.index()));
+            assert_eq!(Ok(*region), GermanRegion::try_from(region.index()));
+        }
+        assert_eq!(Ok(BadenWuerttemberg), GermanRegion::try_from(0));
+        assert_eq!(Ok(Thueringen), GermanRegion::try_from(15));
+        let error = GermanRegion::try_from(16).unwrap_err();
+        assert_eq!(16, error.index());
+    }
+
+    #[test]
+    fn movable_and_fixed_holidays() {
+        let movable: Vec<_> = Bayern
+            .movable_holidays_in_year(2019)
+            .into_iter()
+            .map(|(_, holiday)| holiday)
+            .collect();
+        let fixed: Vec<_> = Bayern
+            .fixed_holidays_in_year(2019)
+            .into_iter()
+            .map(|(_, holiday)| holiday)
+            .collect();
+        assert!(movable.contains(&Karfreitag));
+        assert!(!movable.contains(&Neujahr));
+        assert!(fixed.contains(&Neujahr));
+        assert!(!fixed.contains(&Karfreitag));
+        assert_eq!(
+            Bayern.holiday_count_in_year(2019),
+            movable.len() + fixed.len()
+        );
+    }
+
+    #[test]
+    fn holiday_stream_is_increasing() {
+        let start = ymd(2019, 6, 1);
+        let holidays: Vec<_> = Bayern.holiday_stream_from(start).take(15).collect();
+        assert_eq!(15, holidays.len());
+        assert!(holidays[0].0 >= start);
+        assert!(holidays.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(
+            Some(&(ymd(2020, 1, 1), Neujahr)),
+            holidays.iter().find(|(date, _)| date.year() == 2020)
+        );
+    }
+
+    #[test]
+    fn working_holidays() {
+        // Neujahr, Erster Mai and Erster Weihnachtsfeiertag fall on weekends.
+        assert_eq!(13, Bayern.holiday_count_in_year(2022));
+        assert_eq!(10, Bayern.working_holidays_in_year(2022));
+        // Erster Mai and Christi Himmelfahrt coincide,
+        // Heilige Drei Könige and Allerheiligen fall on weekends.
+        assert_eq!(13, Bayern.holiday_count_in_year(2008));
+        assert_eq!(10, Bayern.working_holidays_in_year(2008));
+        assert_eq!(0, Bayern.working_holidays_in_year(1994));
+    }
+
+    #[test]
+    fn reformationstag_2017_by_date() {
+        let reformationstag = |year| ymd(year, 10, 31);
+        for region in &[Berlin, Hessen] {
+            assert_eq!(
+                Some(Reformationstag),
+                reformationstag(2017).public_holiday_in(*region)
+            );
+            assert_eq!(None, reformationstag(2016).public_holiday_in(*region));
+            assert_eq!(None, reformationstag(2018).public_holiday_in(*region));
+        }
+    }
+
+    #[test]
+    fn populations() {
+        assert_eq!(18_139_116, NordrheinWestfalen.population());
+        assert_eq!(684_864, Bremen.population());
+        let total: u64 = GermanRegion::all()
+            .iter()
+            .map(|region| region.population())
+            .sum();
+        assert!(total > 84_000_000 && total < 85_000_000);
+    }
+
+    #[test]
+    fn longest_holiday_gaps() {
+        assert_eq!(
+            (ymd(2019, 6, 11), ymd(2019, 10, 2), 114),
+            Berlin.longest_holiday_gap_in_year(2019)
+        );
+        assert_eq!(
+            (ymd(2019, 1, 7), ymd(2019, 4, 18), 102),
+            Bayern.longest_holiday_gap_in_year(2019)
+        );
+        assert_eq!(
+            (ymd(1994, 1, 1), ymd(1994, 12, 31), 365),
+            Berlin.longest_holiday_gap_in_year(1994)
+        );
+        let (_, last_day, _) = Berlin.longest_holiday_gap_in_year(NaiveDate::MAX.year());
+        assert_eq!(NaiveDate::MAX.year(), last_day.year());
+        assert_eq!(
+            (NaiveDate::MAX, NaiveDate::MAX, 1),
+            Berlin.longest_holiday_gap_in_year(i32::MAX)
+        );
+        assert_eq!(
+            (NaiveDate::MIN, NaiveDate::MIN, 1),
+            Berlin.longest_holiday_gap_in_year(i32::MIN)
+        );
+    }
+
+    #[test]
+    fn holidays_marking_today() {
+        let today = ymd(2019, 10, 3);
+        let holidays = Bayern.holidays_in_year_marking_today(2019, today);
+        assert_eq!(Bayern.holiday_count_in_year(2019), holidays.len());
+        let marked: Vec<_> = holidays
+            .iter()
+            .filter(|(_, _, is_today)| *is_today)
+            .collect();
+        assert_eq!(vec![&(today, TagDerDeutschenEinheit, true)], marked);
+        let tomorrow = today.succ_opt().unwrap();
+        assert!(Bayern
+            .holidays_in_year_marking_today(2019, tomorrow)
+            .iter()
+            .all(|(_, _, is_today)| !is_today));
+    }
+
+    #[test]
+    fn holiday_difference_between_regions() {
+        assert_eq!(
+            (
+                vec![
+                    HeiligeDreiKoenige,
+                    Fronleichnam,
+                    MariaeHimmelfahrt,
+                    Allerheiligen
+                ],
+                vec![Frauentag]
+            ),
+            Bayern.holiday_difference(Berlin, 2019)
+        );
+        assert_eq!(
+            (vec![], vec![]),
+            Hamburg.holiday_difference(SchleswigHolstein, 2019)
+        );
+    }
+
+    #[test]
+    fn holidays_including_sundays() {
+        for region in GermanRegion::all() {
+            let holidays = region.holidays_in_year_including_sundays(2019);
+            assert_eq!(region.holidays_in_year(2019).len() + 2, holidays.len());
+            assert!(holidays.contains(&Ostersonntag));
+            assert!(holidays.contains(&Pfingstsonntag));
+            assert!(region.holidays_in_year_including_sundays(1994).is_empty());
+        }
+        assert_eq!(
+            &[Karfreitag, Ostersonntag, Ostermontag],
+            &Berlin.holidays_in_year_including_sundays(2019)[2..5]
+        );
+    }
 }
@@ -0,0 +1,84 @@
+//! Building blocks for calculating further holidays which are not covered by `GermanHoliday`.
+
+use crate::holidays::relative_to_easter_sunday;
+use crate::EasterMethod;
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+/// Calculates the date with the given offset in days relative to easter sunday.
+///
+/// Uses the Gregorian easter date as calculated by `computus`.
+/// Returns `None` for years rejected by the algorithm, i.e. outside of 1583 to 9999.
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use holiday_de::util::easter_based_date;
+///
+/// let aschermittwoch = easter_based_date(2019, -46);
+/// assert_eq!(NaiveDate::from_ymd_opt(2019, 3, 6), aschermittwoch);
+/// ```
+pub fn easter_based_date(year: i32, offset_days: i64) -> Option<NaiveDate> {
+    relative_to_easter_sunday(year, offset_days, EasterMethod::Gregorian)
+}
+
+/// Calculates the Gregorian easter sunday, the anchor of all movable holidays.
+///
+/// Returns `None` for years outside of 1583 to 9999.
+pub fn easter_sunday(year: i32) -> Option<NaiveDate> {
+    easter_based_date(year, 0)
+}
+
+/// Returns the nth occurrence, starting at 1, of the weekday within the month,
+/// e.g. the second sunday of May for Muttertag.
+///
+/// `None` if there is no such occurrence, e.g. for a fifth sunday or for `n = 0`.
+pub fn nth_weekday_of_month(year: i32, month: u32, weekday: Weekday, n: u8) -> Option<NaiveDate> {
+    let first_day = NaiveDate::from_ymd_opt(year, month, 1)?;
+    let days_until_weekday =
+        (7 + weekday.num_days_from_monday() - first_day.weekday().num_days_from_monday()) % 7;
+    let weeks = u32::from(n.checked_sub(1)?);
+    let date =
+        first_day.checked_add_signed(Duration::days(i64::from(days_until_weekday + 7 * weeks)))?;
+    if date.month() == month && date.year() == year {
+        Some(date)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::ymd;
+
+    #[test]
+    fn easter_sundays() {
+        assert_eq!(Some(ymd(2016, 3, 27)), easter_sunday(2016));
+        assert_eq!(Some(ymd(2017, 4, 16)), easter_sunday(2017));
+        assert_eq!(Some(ymd(2018, 4, 1)), easter_sunday(2018));
+        assert_eq!(Some(ymd(2019, 4, 21)), easter_sunday(2019));
+        assert_eq!(Some(ymd(2020, 4, 12)), easter_sunday(2020));
+        assert_eq!(None, easter_sunday(10000));
+    }
+
+    #[test]
+    fn second_sunday_of_may() {
+        let second_sunday = |year| nth_weekday_of_month(year, 5, Weekday::Sun, 2);
+        assert_eq!(Some(ymd(2019, 5, 12)), second_sunday(2019));
+        assert_eq!(Some(ymd(2020, 5, 10)), second_sunday(2020));
+        assert_eq!(Some(ymd(2021, 5, 9)), second_sunday(2021));
+        assert_eq!(Some(ymd(2022, 5, 8)), second_sunday(2022));
+        assert_eq!(Some(ymd(2023, 5, 14)), second_sunday(2023));
+    }
+
+    #[test]
+    fn missing_occurrences() {
+        assert_eq!(None, nth_weekday_of_month(2019, 2, Weekday::Sun, 5));
+        assert_eq!(None, nth_weekday_of_month(2019, 5, Weekday::Sun, 0));
+        assert_eq!(None, nth_weekday_of_month(2019, 13, Weekday::Sun, 1));
+        assert_eq!(
+            NaiveDate::from_ymd_opt(2019, 6, 30),
+            nth_weekday_of_month(2019, 6, Weekday::Sun, 5)
+        );
+        assert_eq!(None, nth_weekday_of_month(2019, 6, Weekday::Sun, 255));
+    }
+}